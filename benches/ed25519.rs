@@ -0,0 +1,54 @@
+// cargo bench --features="alloc" --bench ed25519
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn ed25519_verify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ed25519_verify");
+
+    for size in [16, 64, 256] {
+        let keypairs = (0..size)
+            .map(|_| xck::asymmetric::ed25519_gen_keypair())
+            .collect::<Vec<_>>();
+
+        let messages = (0..size)
+            .map(|i| format!("log line {i}").into_bytes())
+            .collect::<Vec<_>>();
+
+        let messages = messages.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        let public_keys = keypairs
+            .iter()
+            .map(|(_, public_key)| *public_key)
+            .collect::<Vec<_>>();
+
+        let signatures = keypairs
+            .iter()
+            .zip(&messages)
+            .map(|((private_key, _), message)| {
+                xck::asymmetric::ed25519_sign(private_key, message).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("single", size), &size, |b, _| {
+            b.iter(|| {
+                for i in 0..size {
+                    xck::asymmetric::ed25519_verify(&public_keys[i], messages[i], &signatures[i])
+                        .unwrap();
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", size), &size, |b, _| {
+            b.iter(|| {
+                xck::asymmetric::ed25519_verify_batch(&public_keys, &messages, &signatures).unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, ed25519_verify);
+criterion_main!(benches);
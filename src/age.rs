@@ -0,0 +1,698 @@
+//! age v1 file encryption. [https://age-encryption.org/v1](https://age-encryption.org/v1)
+//!
+//! Recipients: X25519 (`age1...`) and scrypt passphrase.
+//!
+//! The payload is STREAM with ChaCha20-Poly1305 and 64 KiB chunks, the same as `xck::stream` with a zero nonce prefix.
+//!
+//! Files are interoperable with the reference `age` and `rage` tools, binary or armored.
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use std::io::{self, BufRead, Read, Write};
+
+use base64ct::{Base64Unpadded, Encoding};
+
+use bech32::{FromBase32, ToBase32, Variant};
+
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    asymmetric,
+    hash::{hkdf_sha256, hmac_sha256, hmac_sha256_verify},
+    size::{SIZE_16, SIZE_32},
+    stream::{Algorithm, StreamDecryptor, StreamEncryptor},
+    symmetric,
+    types::{ChaCha20Poly1305Key, Nonce12, X25519PublicKey, X25519SecretKey},
+    Error, ErrorKind, Result,
+};
+
+/// Armor PEM label.
+pub const ARMOR_LABEL: &str = "AGE ENCRYPTED FILE";
+
+/// scrypt work factor (log2 N) used by `encrypt_with_passphrase` in the reference tool.
+pub const DEFAULT_WORK_FACTOR: u8 = 18;
+
+/// Maximum scrypt work factor accepted on decryption.
+pub const MAX_WORK_FACTOR: u8 = 22;
+
+const VERSION_LINE: &str = "age-encryption.org/v1";
+
+const RECIPIENT_HRP: &str = "age";
+
+const IDENTITY_HRP: &str = "age-secret-key-";
+
+const X25519_TAG: &str = "X25519";
+
+const X25519_INFO: &[u8] = b"age-encryption.org/v1/X25519";
+
+const SCRYPT_TAG: &str = "scrypt";
+
+const SCRYPT_SALT_LABEL: &[u8] = b"age-encryption.org/v1/scrypt";
+
+const FILE_KEY_SIZE: usize = SIZE_16;
+
+const PAYLOAD_NONCE_SIZE: usize = SIZE_16;
+
+// Wrapped file key and tag.
+const STANZA_BODY_SIZE: usize = FILE_KEY_SIZE + SIZE_16;
+
+const COLUMNS: usize = 64;
+
+// Upper bound of a header line. It is far above any line of a valid header.
+const LINE_MAX: u64 = 4096;
+
+/// Encode an X25519 public-key as an age recipient, `age1...`.
+pub fn encode_recipient(public_key: &X25519PublicKey) -> String {
+    bech32::encode(
+        RECIPIENT_HRP,
+        public_key.as_bytes().to_base32(),
+        Variant::Bech32,
+    )
+    .unwrap()
+}
+
+/// Decode an age recipient, `age1...`.
+pub fn decode_recipient(recipient: &str) -> Result<X25519PublicKey> {
+    let bytes = bech32_decode(recipient, RECIPIENT_HRP).ok_or(Error::new(
+        ErrorKind::InvalidEncoding,
+        "invalid age recipient.".to_owned(),
+    ))?;
+
+    X25519PublicKey::try_from(bytes.as_slice())
+}
+
+/// Encode an X25519 private-key as an age identity, `AGE-SECRET-KEY-1...`.
+pub fn encode_identity(private_key: &X25519SecretKey) -> String {
+    let mut encoded = bech32::encode(
+        IDENTITY_HRP,
+        private_key.as_bytes().to_base32(),
+        Variant::Bech32,
+    )
+    .unwrap();
+
+    let identity = encoded.to_uppercase();
+
+    encoded.zeroize();
+
+    identity
+}
+
+/// Decode an age identity, `AGE-SECRET-KEY-1...`.
+pub fn decode_identity(identity: &str) -> Result<X25519SecretKey> {
+    let bytes = Zeroizing::new(bech32_decode(identity, IDENTITY_HRP).ok_or(Error::new(
+        ErrorKind::InvalidEncoding,
+        "invalid age identity.".to_owned(),
+    ))?);
+
+    X25519SecretKey::try_from(bytes.as_slice())
+}
+
+fn bech32_decode(string: &str, hrp: &str) -> Option<Vec<u8>> {
+    match bech32::decode(string) {
+        Ok((decoded_hrp, data, Variant::Bech32)) if decoded_hrp == hrp => {
+            Vec::<u8>::from_base32(&data).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Parse recipients, one per line. Empty lines and lines starting with `#` are ignored.
+pub fn parse_recipients(text: &str) -> Result<Vec<X25519PublicKey>> {
+    key_lines(text).map(decode_recipient).collect()
+}
+
+/// Parse an identity file, such as the output of `age-keygen`.
+///
+/// Empty lines and lines starting with `#` are ignored.
+pub fn parse_identities(text: &str) -> Result<Vec<X25519SecretKey>> {
+    key_lines(text).map(decode_identity).collect()
+}
+
+fn key_lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Armor. PEM with the `AGE ENCRYPTED FILE` label.
+pub fn armor(bytes: &[u8]) -> Result<String> {
+    pem_rfc7468::encode_string(ARMOR_LABEL, pem_rfc7468::LineEnding::LF, bytes)
+        .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))
+}
+
+/// Dearmor. Leading and trailing whitespace is ignored.
+pub fn dearmor(armored: &[u8]) -> Result<Vec<u8>> {
+    let (label, bytes) = pem_rfc7468::decode_vec(armored.trim_ascii())
+        .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?;
+
+    if label != ARMOR_LABEL {
+        Err(Error::new(
+            ErrorKind::UnsupportedLabel,
+            format!("unsupported pem label: {label}."),
+        ))?
+    }
+
+    Ok(bytes)
+}
+
+/// Whether bytes begin with the armor, after optional whitespace.
+pub fn is_armored(bytes: &[u8]) -> bool {
+    bytes
+        .trim_ascii_start()
+        .starts_with(b"-----BEGIN AGE ENCRYPTED FILE-----")
+}
+
+struct Stanza {
+    args: Vec<String>,
+    body: Vec<u8>,
+}
+
+fn wrap_file_key(key: &[u8], file_key: &[u8]) -> Result<Vec<u8>> {
+    symmetric::chacha20_poly1305_encrypt_alloc(
+        &ChaCha20Poly1305Key::try_from(key)?,
+        &Nonce12::from([0u8; 12]),
+        &[],
+        file_key,
+    )
+}
+
+fn unwrap_file_key(key: &[u8], body: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+    symmetric::chacha20_poly1305_decrypt_alloc(
+        &ChaCha20Poly1305Key::try_from(key)?,
+        &Nonce12::from([0u8; 12]),
+        &[],
+        body,
+    )
+    .map(Zeroizing::new)
+}
+
+fn x25519_wrap_key(
+    private_key: &X25519SecretKey,
+    their_public_key: &X25519PublicKey,
+    ephemeral_public_key: &X25519PublicKey,
+    recipient: &X25519PublicKey,
+) -> Result<Zeroizing<[u8; SIZE_32]>> {
+    let shared_secret = asymmetric::x25519_diffie_hellman(private_key, their_public_key);
+
+    if shared_secret
+        .as_bytes()
+        .iter()
+        .fold(0, |acc, byte| acc | byte)
+        == 0
+    {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "the public-key is a low order point.".to_owned(),
+        ))?
+    }
+
+    let mut wrap_key = Zeroizing::new([0u8; SIZE_32]);
+
+    hkdf_sha256(
+        &[
+            ephemeral_public_key.as_bytes().as_slice(),
+            recipient.as_bytes(),
+        ]
+        .concat(),
+        shared_secret.as_bytes(),
+        X25519_INFO,
+        wrap_key.as_mut(),
+    )?;
+
+    Ok(wrap_key)
+}
+
+fn x25519_stanza(recipient: &X25519PublicKey, file_key: &[u8]) -> Result<Stanza> {
+    let (ephemeral_private_key, ephemeral_public_key) = asymmetric::x25519_gen_keypair();
+
+    let wrap_key = x25519_wrap_key(
+        &ephemeral_private_key,
+        recipient,
+        &ephemeral_public_key,
+        recipient,
+    )?;
+
+    Ok(Stanza {
+        args: alloc::vec![
+            X25519_TAG.to_owned(),
+            Base64Unpadded::encode_string(ephemeral_public_key.as_bytes()),
+        ],
+        body: wrap_file_key(wrap_key.as_slice(), file_key)?,
+    })
+}
+
+// None if the stanza is not for the identity.
+fn x25519_unwrap(
+    identity: &X25519SecretKey,
+    stanza: &Stanza,
+) -> Result<Option<Zeroizing<Vec<u8>>>> {
+    let ephemeral_public_key = match stanza.args.as_slice() {
+        [_, share] => Base64Unpadded::decode_vec(share)
+            .ok()
+            .and_then(|bytes| X25519PublicKey::try_from(bytes.as_slice()).ok()),
+        _ => None,
+    }
+    .filter(|_| stanza.body.len() == STANZA_BODY_SIZE)
+    .ok_or(invalid_header())?;
+
+    let wrap_key = x25519_wrap_key(
+        identity,
+        &ephemeral_public_key,
+        &ephemeral_public_key,
+        &asymmetric::x25519_gen_public_key(identity),
+    )?;
+
+    match unwrap_file_key(wrap_key.as_slice(), &stanza.body) {
+        Err(err) if err.kind() == ErrorKind::AuthenticationFailed => Ok(None),
+        Err(err) => Err(err),
+        Ok(file_key) => Ok(Some(file_key)),
+    }
+}
+
+fn scrypt_key(passphrase: &[u8], salt: &[u8], work_factor: u8) -> Result<Zeroizing<[u8; SIZE_32]>> {
+    let params = scrypt::Params::new(work_factor, 8, 1, SIZE_32).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidParameter,
+            format!("invalid scrypt work factor: {work_factor}."),
+        )
+    })?;
+
+    let mut key = Zeroizing::new([0u8; SIZE_32]);
+
+    scrypt::scrypt(
+        passphrase,
+        &[SCRYPT_SALT_LABEL, salt].concat(),
+        &params,
+        key.as_mut(),
+    )
+    .map_err(|_| Error::new(ErrorKind::Other, "scrypt failed.".to_owned()))?;
+
+    Ok(key)
+}
+
+fn scrypt_stanza(passphrase: &[u8], work_factor: u8, file_key: &[u8]) -> Result<Stanza> {
+    let salt = crate::rand::gen_16();
+
+    let key = scrypt_key(passphrase, &salt, work_factor)?;
+
+    Ok(Stanza {
+        args: alloc::vec![
+            SCRYPT_TAG.to_owned(),
+            Base64Unpadded::encode_string(&salt),
+            work_factor.to_string(),
+        ],
+        body: wrap_file_key(key.as_slice(), file_key)?,
+    })
+}
+
+fn scrypt_unwrap(passphrase: &[u8], stanza: &Stanza) -> Result<Zeroizing<Vec<u8>>> {
+    let (salt, work_factor) = match stanza.args.as_slice() {
+        [_, salt, work_factor]
+            if work_factor.bytes().all(|byte| byte.is_ascii_digit())
+                && !work_factor.starts_with('0') =>
+        {
+            (
+                Base64Unpadded::decode_vec(salt).ok(),
+                work_factor.parse::<u8>().ok(),
+            )
+        }
+        _ => (None, None),
+    };
+
+    let (salt, work_factor) = salt
+        .filter(|salt| salt.len() == SIZE_16 && stanza.body.len() == STANZA_BODY_SIZE)
+        .zip(work_factor)
+        .ok_or(invalid_header())?;
+
+    if work_factor > MAX_WORK_FACTOR {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            format!("the scrypt work factor is too high: {work_factor}."),
+        ))?
+    }
+
+    let key = scrypt_key(passphrase, &salt, work_factor)?;
+
+    unwrap_file_key(key.as_slice(), &stanza.body).map_err(|err| match err.kind() {
+        ErrorKind::AuthenticationFailed => Error::new(
+            ErrorKind::AuthenticationFailed,
+            "the passphrase does not match.".to_owned(),
+        ),
+        _ => err,
+    })
+}
+
+fn invalid_header() -> Error {
+    Error::new(
+        ErrorKind::InvalidEncoding,
+        "the age header is malformed.".to_owned(),
+    )
+}
+
+fn header_mac_key(file_key: &[u8]) -> Zeroizing<[u8; SIZE_32]> {
+    let mut key = Zeroizing::new([0u8; SIZE_32]);
+
+    hkdf_sha256(&[], file_key, b"header", key.as_mut()).unwrap();
+
+    key
+}
+
+fn payload_key(file_key: &[u8], nonce: &[u8]) -> Zeroizing<[u8; SIZE_32]> {
+    let mut key = Zeroizing::new([0u8; SIZE_32]);
+
+    hkdf_sha256(nonce, file_key, b"payload", key.as_mut()).unwrap();
+
+    key
+}
+
+// The nonce of each chunk is an 11-byte counter and the last flag. The stream nonce prefix is zero.
+fn payload_nonce_prefix() -> Vec<u8> {
+    alloc::vec![0u8; crate::stream::nonce_prefix_len(Algorithm::ChaCha20Poly1305)]
+}
+
+// Header up to and including "---", the input of the header mac.
+fn header_bytes(stanzas: &[Stanza]) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    buf.extend_from_slice(VERSION_LINE.as_bytes());
+
+    buf.push(b'\n');
+
+    for stanza in stanzas {
+        buf.extend_from_slice(b"-> ");
+
+        buf.extend_from_slice(stanza.args.join(" ").as_bytes());
+
+        buf.push(b'\n');
+
+        let body = Base64Unpadded::encode_string(&stanza.body);
+
+        for line in body.as_bytes().chunks(COLUMNS) {
+            buf.extend_from_slice(line);
+
+            buf.push(b'\n');
+        }
+
+        // The body always ends with a line shorter than 64 columns, possibly empty.
+        if body.len() % COLUMNS == 0 {
+            buf.push(b'\n');
+        }
+    }
+
+    buf.extend_from_slice(b"---");
+
+    buf
+}
+
+fn write_file(
+    stanzas: Vec<Stanza>,
+    file_key: &[u8],
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    let mut header = header_bytes(&stanzas);
+
+    let mac = hmac_sha256(header_mac_key(file_key).as_slice(), &header);
+
+    header.push(b' ');
+
+    header.extend_from_slice(Base64Unpadded::encode_string(&mac).as_bytes());
+
+    header.push(b'\n');
+
+    writer.write_all(&header)?;
+
+    let nonce = crate::rand::gen_16();
+
+    writer.write_all(&nonce)?;
+
+    let mut encryptor = StreamEncryptor::with_nonce_prefix(
+        Algorithm::ChaCha20Poly1305,
+        payload_key(file_key, &nonce).as_slice(),
+        payload_nonce_prefix(),
+        writer,
+    )?;
+
+    io::copy(reader, &mut encryptor)?;
+
+    encryptor.finish()?;
+
+    Ok(())
+}
+
+/// Encrypt to X25519 recipients.
+///
+/// Encrypts everything from reader and writes the binary age file to writer. Use `armor` for the armored file.
+///
+/// # Example
+/// ```
+/// let (private_key, public_key) = xck::asymmetric::x25519_gen_keypair();
+///
+/// let mut encrypted = Vec::new();
+///
+/// xck::age::encrypt(&[public_key], &mut &b"hello"[..], &mut encrypted).unwrap();
+///
+/// let mut plain = Vec::new();
+///
+/// xck::age::decrypt(&[private_key], &mut encrypted.as_slice(), &mut plain).unwrap();
+///
+/// println!("{:?}",plain);
+/// ```
+pub fn encrypt(
+    recipients: &[X25519PublicKey],
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    if recipients.is_empty() {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "at least one recipient is required.".to_owned(),
+        ))?
+    }
+
+    let file_key = Zeroizing::new(crate::rand::gen_16());
+
+    let stanzas = recipients
+        .iter()
+        .map(|recipient| x25519_stanza(recipient, file_key.as_slice()))
+        .collect::<Result<Vec<Stanza>>>()?;
+
+    write_file(stanzas, file_key.as_slice(), reader, writer)
+}
+
+/// Encrypt with a passphrase. The file key is wrapped with scrypt.
+///
+/// work_factor is log2 of the scrypt N. `DEFAULT_WORK_FACTOR` is 18.
+pub fn encrypt_with_passphrase(
+    passphrase: &[u8],
+    work_factor: u8,
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    let file_key = Zeroizing::new(crate::rand::gen_16());
+
+    let stanza = scrypt_stanza(passphrase, work_factor, file_key.as_slice())?;
+
+    write_file(alloc::vec![stanza], file_key.as_slice(), reader, writer)
+}
+
+// Read one header line. The line is appended to raw without the newline.
+fn read_line(reader: &mut impl BufRead, raw: &mut Vec<u8>) -> Result<String> {
+    let mut line = Vec::new();
+
+    reader.take(LINE_MAX).read_until(b'\n', &mut line)?;
+
+    if line.pop() != Some(b'\n') {
+        Err(invalid_header())?
+    }
+
+    raw.extend_from_slice(&line);
+
+    raw.push(b'\n');
+
+    String::from_utf8(line).map_err(|_| invalid_header())
+}
+
+// Returns the stanzas, the mac input and the mac.
+fn read_header(reader: &mut impl BufRead) -> Result<(Vec<Stanza>, Vec<u8>, Vec<u8>)> {
+    let mut raw = Vec::new();
+
+    let version = read_line(reader, &mut raw).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidEncoding,
+            "this is not an age file.".to_owned(),
+        )
+    })?;
+
+    if version != VERSION_LINE {
+        if version.starts_with("age-encryption.org/") {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported age version: {version}."),
+            ))?
+        }
+
+        Err(Error::new(
+            ErrorKind::InvalidEncoding,
+            "this is not an age file.".to_owned(),
+        ))?
+    }
+
+    let mut stanzas = Vec::new();
+
+    loop {
+        let start = raw.len();
+
+        let line = read_line(reader, &mut raw)?;
+
+        if let Some(mac) = line.strip_prefix("--- ") {
+            raw.truncate(start + 3);
+
+            let mac = Base64Unpadded::decode_vec(mac).map_err(|_| invalid_header())?;
+
+            if stanzas.is_empty() || mac.len() != SIZE_32 {
+                Err(invalid_header())?
+            }
+
+            return Ok((stanzas, raw, mac));
+        }
+
+        let args = line
+            .strip_prefix("-> ")
+            .map(|args| args.split(' ').map(str::to_owned).collect::<Vec<String>>())
+            .filter(|args| {
+                args.iter().all(|arg| {
+                    !arg.is_empty() && arg.bytes().all(|byte| (0x21..=0x7e).contains(&byte))
+                })
+            })
+            .ok_or(invalid_header())?;
+
+        let mut body = String::new();
+
+        loop {
+            let line = read_line(reader, &mut raw)?;
+
+            if line.len() > COLUMNS {
+                Err(invalid_header())?
+            }
+
+            body.push_str(&line);
+
+            if line.len() < COLUMNS {
+                break;
+            }
+        }
+
+        stanzas.push(Stanza {
+            args,
+            body: Base64Unpadded::decode_vec(&body).map_err(|_| invalid_header())?,
+        });
+    }
+}
+
+fn read_payload(
+    file_key: &[u8],
+    header: &[u8],
+    mac: &[u8],
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    hmac_sha256_verify(header_mac_key(file_key).as_slice(), header, mac).map_err(|_| {
+        Error::new(
+            ErrorKind::AuthenticationFailed,
+            "the header mac does not match.".to_owned(),
+        )
+    })?;
+
+    let mut nonce = [0u8; PAYLOAD_NONCE_SIZE];
+
+    reader.read_exact(&mut nonce).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidEncoding,
+            "the payload is truncated.".to_owned(),
+        )
+    })?;
+
+    let mut decryptor = StreamDecryptor::with_nonce_prefix(
+        Algorithm::ChaCha20Poly1305,
+        payload_key(file_key, &nonce).as_slice(),
+        payload_nonce_prefix(),
+        reader,
+    )?;
+
+    io::copy(&mut decryptor, writer)?;
+
+    Ok(())
+}
+
+/// Decrypt with X25519 identities.
+///
+/// Reads a binary age file from reader and writes the plain bytes to writer. Use `dearmor` for the armored file.
+///
+/// Plain bytes of the verified chunks may already have been written when an error is returned.
+pub fn decrypt(
+    identities: &[X25519SecretKey],
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    let mut reader = io::BufReader::new(reader);
+
+    let (stanzas, header, mac) = read_header(&mut reader)?;
+
+    if stanzas.iter().any(|stanza| stanza.args[0] == SCRYPT_TAG) {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "the file is encrypted with a passphrase.".to_owned(),
+        ))?
+    }
+
+    let mut file_key = None;
+
+    // Stanzas of unknown types are skipped.
+    'stanzas: for stanza in stanzas.iter().filter(|stanza| stanza.args[0] == X25519_TAG) {
+        for identity in identities {
+            if let Some(key) = x25519_unwrap(identity, stanza)? {
+                file_key = Some(key);
+                break 'stanzas;
+            }
+        }
+    }
+
+    let file_key = file_key.ok_or(Error::new(
+        ErrorKind::AuthenticationFailed,
+        "no identity matched any of the recipients.".to_owned(),
+    ))?;
+
+    read_payload(&file_key, &header, &mac, &mut reader, writer)
+}
+
+/// Decrypt with a passphrase.
+///
+/// The scrypt work factor must be `MAX_WORK_FACTOR` or less.
+pub fn decrypt_with_passphrase(
+    passphrase: &[u8],
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    let mut reader = io::BufReader::new(reader);
+
+    let (stanzas, header, mac) = read_header(&mut reader)?;
+
+    let file_key = match stanzas.as_slice() {
+        [stanza] if stanza.args[0] == SCRYPT_TAG => scrypt_unwrap(passphrase, stanza)?,
+        stanzas if stanzas.iter().any(|stanza| stanza.args[0] == SCRYPT_TAG) => {
+            Err(invalid_header())?
+        }
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            "the file is not encrypted with a passphrase.".to_owned(),
+        ))?,
+    };
+
+    read_payload(&file_key, &header, &mac, &mut reader, writer)
+}
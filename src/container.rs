@@ -0,0 +1,399 @@
+//! XCK container format, version 1.
+//!
+//! All integers are big endian.
+//!
+//! | field      | size                                   |
+//! |------------|----------------------------------------|
+//! | magic      | 4-byte, `XCK\0`                        |
+//! | version    | 1-byte, `1`                            |
+//! | algorithm  | 1-byte, see `algorithm_id`             |
+//! | kdf        | 1-byte, `0` none, `1` Argon2id         |
+//! | kdf params | Argon2id only: salt 16-byte, memory cost u32, time cost u32, parallelism u32 |
+//! | nonce      | 12-byte, or 24-byte for XChaCha20-Poly1305 |
+//! | cipher     | cipher bytes followed by the 16-byte tag |
+//!
+//! The whole header (magic to nonce) is bound to the cipher as aad, followed by the caller's aad.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use zeroize::Zeroizing;
+
+use crate::{size::SIZE_16, symmetric, Error, ErrorKind, Result};
+
+pub use crate::symmetric::AeadAlgorithm as Algorithm;
+
+/// Magic bytes. `XCK\0`.
+pub const MAGIC: [u8; 4] = [88, 67, 75, 0];
+
+/// Current container version.
+pub const VERSION: u8 = 1;
+
+/// Maximum Argon2id memory cost in KiB accepted on sealing and opening. 1 GiB.
+pub const MAX_MEMORY_COST: u32 = 1048576;
+
+/// Maximum Argon2id time cost (number of iterations) accepted on sealing and opening.
+pub const MAX_TIME_COST: u32 = 16;
+
+/// Maximum Argon2id parallelism accepted on sealing and opening.
+pub const MAX_PARALLELISM: u32 = 16;
+
+const KDF_NONE: u8 = 0;
+
+const KDF_ARGON2ID: u8 = 1;
+
+const ARGON2ID_PARAMS_SIZE: usize = SIZE_16 + 4 + 4 + 4;
+
+/// Argon2id parameters stored in the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2idParams {
+    pub salt: [u8; SIZE_16],
+    pub memory_cost: u32,
+    pub time_cost: u32,
+    pub parallelism: u32,
+}
+
+impl Argon2idParams {
+    /// Argon2id parameters with a random 16-byte salt.
+    ///
+    /// Memory cost is in KiB.
+    pub fn new(memory_cost: u32, time_cost: u32, parallelism: u32) -> Self {
+        Self {
+            salt: crate::rand::gen_16(),
+            memory_cost,
+            time_cost,
+            parallelism,
+        }
+    }
+}
+
+impl Default for Argon2idParams {
+    /// Memory cost: 19456 KiB, Time(number of iterations): 2, Parallelism: 1. With a random salt.
+    fn default() -> Self {
+        Self::new(
+            argon2::Params::DEFAULT_M_COST,
+            argon2::Params::DEFAULT_T_COST,
+            argon2::Params::DEFAULT_P_COST,
+        )
+    }
+}
+
+/// Container header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub algorithm: Algorithm,
+    pub kdf: Option<Argon2idParams>,
+    pub nonce: Vec<u8>,
+}
+
+impl Header {
+    /// Serialize the header.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(7 + ARGON2ID_PARAMS_SIZE + self.nonce.len());
+
+        buf.extend_from_slice(&MAGIC);
+
+        buf.push(VERSION);
+
+        buf.push(algorithm_id(self.algorithm));
+
+        match &self.kdf {
+            None => buf.push(KDF_NONE),
+            Some(params) => {
+                buf.push(KDF_ARGON2ID);
+                buf.extend_from_slice(&params.salt);
+                buf.extend_from_slice(&params.memory_cost.to_be_bytes());
+                buf.extend_from_slice(&params.time_cost.to_be_bytes());
+                buf.extend_from_slice(&params.parallelism.to_be_bytes());
+            }
+        }
+
+        buf.extend_from_slice(&self.nonce);
+
+        buf
+    }
+
+    /// Parse the header at the head of bytes.
+    ///
+    /// Returns the header and its length in bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<(Self, usize)> {
+        let mut reader = HeaderReader { bytes, position: 0 };
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            Err(Error::new(
+                ErrorKind::InvalidEncoding,
+                "the magic bytes do not match, this is not an xck container.".to_owned(),
+            ))?
+        }
+
+        let version = reader.take(1)?[0];
+
+        if version != VERSION {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported container version: {version}."),
+            ))?
+        }
+
+        let algorithm = algorithm_from_id(reader.take(1)?[0])?;
+
+        let kdf = match reader.take(1)?[0] {
+            KDF_NONE => None,
+            KDF_ARGON2ID => Some(Argon2idParams {
+                salt: reader.take(SIZE_16)?.try_into().unwrap(),
+                memory_cost: reader.take_u32()?,
+                time_cost: reader.take_u32()?,
+                parallelism: reader.take_u32()?,
+            }),
+            id => Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("unknown kdf id: {id}."),
+            ))?,
+        };
+
+        let nonce = reader.take(algorithm.nonce_len())?.to_vec();
+
+        Ok((
+            Self {
+                algorithm,
+                kdf,
+                nonce,
+            },
+            reader.position,
+        ))
+    }
+}
+
+struct HeaderReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> HeaderReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .bytes
+            .get(self.position..self.position + len)
+            .ok_or(Error::new(
+                ErrorKind::InvalidEncoding,
+                "the container header is truncated.".to_owned(),
+            ))?;
+
+        self.position += len;
+
+        Ok(bytes)
+    }
+
+    fn take_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+}
+
+/// Algorithm id written in the header.
+pub fn algorithm_id(algorithm: Algorithm) -> u8 {
+    match algorithm {
+        Algorithm::Aes128Gcm => 1,
+        Algorithm::Aes192Gcm => 2,
+        Algorithm::Aes256Gcm => 3,
+        Algorithm::ChaCha20Poly1305 => 4,
+        Algorithm::XChaCha20Poly1305 => 5,
+    }
+}
+
+fn algorithm_from_id(id: u8) -> Result<Algorithm> {
+    match id {
+        1 => Ok(Algorithm::Aes128Gcm),
+        2 => Ok(Algorithm::Aes192Gcm),
+        3 => Ok(Algorithm::Aes256Gcm),
+        4 => Ok(Algorithm::ChaCha20Poly1305),
+        5 => Ok(Algorithm::XChaCha20Poly1305),
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("unknown algorithm id: {id}."),
+        )),
+    }
+}
+
+/// Seal.
+///
+/// Encrypts plain with a random nonce and returns the container bytes.
+///
+/// If you want Aad to be empty, use &[].
+///
+/// # Example
+/// ```
+/// let key = xck::rand::gen_32();
+///
+/// let sealed = xck::container::seal(xck::container::Algorithm::XChaCha20Poly1305, &key, &[], b"hello").unwrap();
+///
+/// let plain = xck::container::open(&key, &[], &sealed).unwrap();
+///
+/// println!("{:?}",plain);
+/// ```
+pub fn seal(algorithm: Algorithm, key: &[u8], aad: &[u8], plain: &[u8]) -> Result<Vec<u8>> {
+    seal_with_header(
+        Header {
+            algorithm,
+            kdf: None,
+            nonce: crate::rand::gen_24()[..algorithm.nonce_len()].to_vec(),
+        },
+        key,
+        aad,
+        plain,
+    )
+}
+
+/// Open.
+///
+/// Parses the header and decrypts the container with key.
+pub fn open(key: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+    let (header, len) = Header::from_bytes(sealed)?;
+
+    if header.kdf.is_some() {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "the container is protected by a passphrase.".to_owned(),
+        ))?
+    }
+
+    open_with_header(&header, key, aad, &sealed[..len], &sealed[len..])
+}
+
+/// Seal with passphrase.
+///
+/// The key is derived from passphrase with Argon2id. The salt and parameters are stored in the header.
+///
+/// # Example
+/// ```
+/// let sealed = xck::container::seal_with_passphrase(
+///     xck::container::Algorithm::XChaCha20Poly1305,
+///     b"hello 0123 password",
+///     xck::container::Argon2idParams::default(),
+///     &[],
+///     b"hello",
+/// )
+/// .unwrap();
+///
+/// let plain = xck::container::open_with_passphrase(b"hello 0123 password", &[], &sealed).unwrap();
+///
+/// println!("{:?}",plain);
+/// ```
+pub fn seal_with_passphrase(
+    algorithm: Algorithm,
+    passphrase: &[u8],
+    params: Argon2idParams,
+    aad: &[u8],
+    plain: &[u8],
+) -> Result<Vec<u8>> {
+    let key = argon2id(passphrase, &params, algorithm.key_len())?;
+
+    seal_with_header(
+        Header {
+            algorithm,
+            kdf: Some(params),
+            nonce: crate::rand::gen_24()[..algorithm.nonce_len()].to_vec(),
+        },
+        &key,
+        aad,
+        plain,
+    )
+}
+
+/// Open with passphrase.
+///
+/// Derives the key with the Argon2id salt and parameters in the header and decrypts the container.
+///
+/// The parameters must be `MAX_MEMORY_COST`, `MAX_TIME_COST` and `MAX_PARALLELISM` or less.
+pub fn open_with_passphrase(passphrase: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+    let (header, len) = Header::from_bytes(sealed)?;
+
+    let params = header.kdf.ok_or(Error::new(
+        ErrorKind::Unsupported,
+        "the container is not protected by a passphrase.".to_owned(),
+    ))?;
+
+    let key = argon2id(passphrase, &params, header.algorithm.key_len())?;
+
+    open_with_header(&header, &key, aad, &sealed[..len], &sealed[len..])
+}
+
+fn argon2id(passphrase: &[u8], params: &Argon2idParams, len: usize) -> Result<Zeroizing<Vec<u8>>> {
+    if params.memory_cost > MAX_MEMORY_COST
+        || params.time_cost > MAX_TIME_COST
+        || params.parallelism > MAX_PARALLELISM
+    {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            format!(
+                "the argon2id parameters are too high: memory cost {}, time cost {}, parallelism {}.",
+                params.memory_cost, params.time_cost, params.parallelism
+            ),
+        ))?
+    }
+
+    let mut key = Zeroizing::new(alloc::vec![0u8; len]);
+
+    argon2::Argon2::new(
+        argon2::Algorithm::Argon2id,
+        argon2::Version::V0x13,
+        argon2::Params::new(
+            params.memory_cost,
+            params.time_cost,
+            params.parallelism,
+            Some(len),
+        )
+        .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?,
+    )
+    .hash_password_into(passphrase, &params.salt, &mut key)
+    .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?;
+
+    Ok(key)
+}
+
+fn seal_with_header(header: Header, key: &[u8], aad: &[u8], plain: &[u8]) -> Result<Vec<u8>> {
+    check_key_len(header.algorithm, key)?;
+
+    let mut buf = header.to_bytes();
+
+    let cipher = symmetric::encrypt_alloc(
+        header.algorithm,
+        key,
+        &header.nonce,
+        &[buf.as_slice(), aad].concat(),
+        plain,
+    )?;
+
+    buf.extend_from_slice(&cipher);
+
+    Ok(buf)
+}
+
+fn open_with_header(
+    header: &Header,
+    key: &[u8],
+    aad: &[u8],
+    header_bytes: &[u8],
+    cipher: &[u8],
+) -> Result<Vec<u8>> {
+    check_key_len(header.algorithm, key)?;
+
+    symmetric::decrypt_alloc(
+        header.algorithm,
+        key,
+        &header.nonce,
+        &[header_bytes, aad].concat(),
+        cipher,
+    )
+}
+
+fn check_key_len(algorithm: Algorithm, key: &[u8]) -> Result<()> {
+    if key.len() != algorithm.key_len() {
+        Err(Error::new(
+            ErrorKind::InvalidKeyLength,
+            format!("the key length must be {}-byte.", algorithm.key_len()),
+        ))?
+    }
+
+    Ok(())
+}
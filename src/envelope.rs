@@ -0,0 +1,333 @@
+//! XCK signature envelope, version 1. A detached Ed25519 signature with its metadata.
+//!
+//! All integers are big endian.
+//!
+//! | field          | size                                              |
+//! |----------------|---------------------------------------------------|
+//! | magic          | 4-byte, `XCKS`                                    |
+//! | version        | 1-byte, `1`                                       |
+//! | algorithm      | 1-byte, see `algorithm_id`                        |
+//! | fingerprint    | 32-byte, SHA256 of the signer's public-key        |
+//! | timestamp      | u64, seconds since the UNIX epoch                 |
+//! | comment length | u16                                               |
+//! | comment        | UTF-8                                             |
+//! | signature      | 64-byte                                           |
+//!
+//! The metadata (magic to comment) is signed as well: the signed message is `SHA512(metadata) || message`, with Ed25519, Ed25519ctx or Ed25519ph (the prehash is SHA512 of the whole).
+//!
+//! The text form is PEM, `-----BEGIN XCK SIGNATURE-----`.
+
+extern crate alloc;
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use std::io::{self, Read};
+
+use crate::{
+    asymmetric,
+    format::LINE_ENDING,
+    hash::{sha256, Sha512Hasher},
+    size::{SIZE_32, SIZE_64},
+    types::{Ed25519PublicKey, Ed25519SecretKey, Ed25519Signature},
+    Error, ErrorKind, Result,
+};
+
+/// Magic bytes. `XCKS`.
+pub const MAGIC: [u8; 4] = [88, 67, 75, 83];
+
+/// Current envelope version.
+pub const VERSION: u8 = 1;
+
+/// PEM label.
+pub const PEM_LABEL: &str = "XCK SIGNATURE";
+
+// magic || version || algorithm || fingerprint || timestamp || comment length
+const FIXED_SIZE: usize = 4 + 1 + 1 + SIZE_32 + 8 + 2;
+
+/// Signature algorithm. (RFC 8032)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    Ed25519,
+    Ed25519ctx,
+    Ed25519ph,
+}
+
+/// Signature envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Envelope {
+    pub algorithm: Algorithm,
+
+    /// SHA256 of the signer's public-key, see `key_fingerprint`.
+    pub fingerprint: [u8; SIZE_32],
+
+    /// Seconds since the UNIX epoch.
+    pub timestamp: u64,
+
+    /// Free text, signed with the message. At most 65535-byte.
+    pub comment: String,
+
+    pub signature: Ed25519Signature,
+}
+
+impl Envelope {
+    /// Serialize the metadata, everything but the signature.
+    pub fn metadata_bytes(&self) -> Result<Vec<u8>> {
+        let comment_len = u16::try_from(self.comment.len()).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidLength,
+                "the comment must be at most 65535-byte.".to_owned(),
+            )
+        })?;
+
+        let mut buf = Vec::with_capacity(FIXED_SIZE + self.comment.len() + SIZE_64);
+
+        buf.extend_from_slice(&MAGIC);
+
+        buf.push(VERSION);
+
+        buf.push(algorithm_id(self.algorithm));
+
+        buf.extend_from_slice(&self.fingerprint);
+
+        buf.extend_from_slice(&self.timestamp.to_be_bytes());
+
+        buf.extend_from_slice(&comment_len.to_be_bytes());
+
+        buf.extend_from_slice(self.comment.as_bytes());
+
+        Ok(buf)
+    }
+
+    /// Serialize the envelope.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut buf = self.metadata_bytes()?;
+
+        buf.extend_from_slice(self.signature.as_bytes());
+
+        Ok(buf)
+    }
+
+    /// Parse the envelope. Trailing bytes are an error.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let truncated = || {
+            Error::new(
+                ErrorKind::InvalidEncoding,
+                "the signature envelope is truncated.".to_owned(),
+            )
+        };
+
+        let fixed = bytes.get(..FIXED_SIZE).ok_or_else(truncated)?;
+
+        if fixed[..4] != MAGIC {
+            Err(Error::new(
+                ErrorKind::InvalidEncoding,
+                "the magic bytes do not match, this is not an xck signature.".to_owned(),
+            ))?
+        }
+
+        if fixed[4] != VERSION {
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported signature envelope version: {}.", fixed[4]),
+            ))?
+        }
+
+        let algorithm = algorithm_from_id(fixed[5])?;
+
+        let comment_len = u16::from_be_bytes(fixed[46..48].try_into().unwrap()) as usize;
+
+        let comment = bytes
+            .get(FIXED_SIZE..FIXED_SIZE + comment_len)
+            .ok_or_else(truncated)?;
+
+        let signature = bytes
+            .get(FIXED_SIZE + comment_len..)
+            .filter(|signature| signature.len() >= SIZE_64)
+            .ok_or_else(truncated)?;
+
+        if signature.len() != SIZE_64 {
+            Err(Error::new(
+                ErrorKind::InvalidLength,
+                "the signature envelope has trailing bytes.".to_owned(),
+            ))?
+        }
+
+        Ok(Self {
+            algorithm,
+            fingerprint: fixed[6..38].try_into().unwrap(),
+            timestamp: u64::from_be_bytes(fixed[38..46].try_into().unwrap()),
+            comment: String::from_utf8(comment.to_vec())
+                .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?,
+            signature: Ed25519Signature::try_from(signature)?,
+        })
+    }
+
+    /// PEM of the envelope, `-----BEGIN XCK SIGNATURE-----`.
+    pub fn to_pem(&self) -> Result<String> {
+        pem_rfc7468::encode_string(PEM_LABEL, LINE_ENDING, &self.to_bytes()?)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))
+    }
+
+    /// Parse the PEM of the envelope.
+    pub fn from_pem(pem: &[u8]) -> Result<Self> {
+        let (label, bytes) = pem_rfc7468::decode_vec(pem)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?;
+
+        if label != PEM_LABEL {
+            Err(Error::new(
+                ErrorKind::UnsupportedLabel,
+                format!("unsupported pem label: {label}."),
+            ))?
+        }
+
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Algorithm id written in the envelope.
+pub fn algorithm_id(algorithm: Algorithm) -> u8 {
+    match algorithm {
+        Algorithm::Ed25519 => 1,
+        Algorithm::Ed25519ctx => 2,
+        Algorithm::Ed25519ph => 3,
+    }
+}
+
+fn algorithm_from_id(id: u8) -> Result<Algorithm> {
+    match id {
+        1 => Ok(Algorithm::Ed25519),
+        2 => Ok(Algorithm::Ed25519ctx),
+        3 => Ok(Algorithm::Ed25519ph),
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("unknown algorithm id: {id}."),
+        )),
+    }
+}
+
+/// Fingerprint of the public-key, SHA256 of its 32 bytes.
+pub fn key_fingerprint(public_key: &Ed25519PublicKey) -> [u8; SIZE_32] {
+    sha256(public_key.as_bytes())
+}
+
+/// Sign everything from reader into an envelope.
+///
+/// context is for Ed25519ctx (not empty) and Ed25519ph, and must be empty for Ed25519. Ed25519ph hashes the reader in a streaming fashion, the others read it into memory.
+///
+/// # Example
+/// ```
+/// use xck::envelope::Algorithm;
+///
+/// let (private_key, public_key) = xck::asymmetric::ed25519_gen_keypair();
+///
+/// let envelope = xck::envelope::sign(&private_key, Algorithm::Ed25519ph, b"", &mut &b"hello"[..], 1700000000, "release").unwrap();
+///
+/// let pem = envelope.to_pem().unwrap();
+///
+/// let envelope = xck::envelope::Envelope::from_pem(pem.as_bytes()).unwrap();
+///
+/// assert!(xck::envelope::verify(&envelope, &public_key, b"", &mut &b"hello"[..]).is_ok());
+///
+/// assert_eq!(envelope.comment, "release");
+/// ```
+pub fn sign(
+    private_key: &Ed25519SecretKey,
+    algorithm: Algorithm,
+    context: &[u8],
+    reader: &mut impl Read,
+    timestamp: u64,
+    comment: &str,
+) -> Result<Envelope> {
+    let mut envelope = Envelope {
+        algorithm,
+        fingerprint: key_fingerprint(&asymmetric::ed25519_gen_public_key(private_key)),
+        timestamp,
+        comment: comment.to_owned(),
+        signature: Ed25519Signature::from([0u8; SIZE_64]),
+    };
+
+    envelope.signature = match signed_message(&envelope, context, reader)? {
+        SignedMessage::Plain(message) => asymmetric::ed25519_sign(private_key, &message)?,
+        SignedMessage::Context(message) => {
+            asymmetric::ed25519ctx_sign(private_key, context, &message)?
+        }
+        SignedMessage::Prehash(prehash) => {
+            asymmetric::ed25519ph_sign(private_key, context, &prehash)?
+        }
+    };
+
+    Ok(envelope)
+}
+
+/// Verify the envelope of everything from reader.
+///
+/// The fingerprint must be of public_key, and context the same as on signing.
+pub fn verify(
+    envelope: &Envelope,
+    public_key: &Ed25519PublicKey,
+    context: &[u8],
+    reader: &mut impl Read,
+) -> Result<()> {
+    if envelope.fingerprint != key_fingerprint(public_key) {
+        Err(Error::new(
+            ErrorKind::AuthenticationFailed,
+            "the signature was made by another key.".to_owned(),
+        ))?
+    }
+
+    let signature = &envelope.signature;
+
+    match signed_message(envelope, context, reader)? {
+        SignedMessage::Plain(message) => {
+            asymmetric::ed25519_verify(public_key, &message, signature)
+        }
+        SignedMessage::Context(message) => {
+            asymmetric::ed25519ctx_verify(public_key, context, &message, signature)
+        }
+        SignedMessage::Prehash(prehash) => {
+            asymmetric::ed25519ph_verify(public_key, context, &prehash, signature)
+        }
+    }
+}
+
+enum SignedMessage {
+    Plain(Vec<u8>),
+    Context(Vec<u8>),
+    Prehash([u8; SIZE_64]),
+}
+
+// SHA512(metadata) || message
+fn signed_message(
+    envelope: &Envelope,
+    context: &[u8],
+    reader: &mut impl Read,
+) -> Result<SignedMessage> {
+    let mut hasher = Sha512Hasher::new();
+
+    hasher.update(&envelope.metadata_bytes()?);
+
+    let metadata_hash = hasher.finalize();
+
+    if envelope.algorithm == Algorithm::Ed25519ph {
+        hasher.reset();
+
+        hasher.update(&metadata_hash);
+
+        io::copy(reader, &mut hasher)?;
+
+        return Ok(SignedMessage::Prehash(hasher.finalize()));
+    }
+
+    let mut message = metadata_hash.to_vec();
+
+    reader.read_to_end(&mut message)?;
+
+    match envelope.algorithm {
+        Algorithm::Ed25519 if !context.is_empty() => Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "a context is for Ed25519ctx and Ed25519ph.".to_owned(),
+        )),
+        Algorithm::Ed25519 => Ok(SignedMessage::Plain(message)),
+        _ => Ok(SignedMessage::Context(message)),
+    }
+}
@@ -1,8 +1,26 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use std::io::{self, Write};
+
 use blake3::Hasher as Blake3;
 
+use hmac::{Hmac, Mac};
+
 use sha2::{digest::Digest, Sha256, Sha512, Sha512_256};
 
-use crate::size::{SIZE_32, SIZE_64};
+use sha3::{
+    digest::{ExtendableOutput, XofReader},
+    Keccak256, Sha3_256, Sha3_512, Shake128, Shake256,
+};
+
+use crate::{
+    size::{SIZE_16, SIZE_32, SIZE_64, SIZE_8},
+    Error, ErrorKind, Result,
+};
 
 /// BLAKE3 KDF default context.
 pub const BLAKE3_DEFAULT_CONTEXT: &str = "XCK VERSION 0.0.1 BLAKE3 DEFAULT CONTEXT";
@@ -30,18 +48,18 @@ pub fn blake3_mac(key: &[u8; SIZE_32], message: &[u8]) -> [u8; SIZE_32] {
 /// println!("{:?}",key);
 /// ```
 pub fn blake3_kdf(context: &str, material: &[u8]) -> [u8; SIZE_32] {
-    blake3::derive_key(context, material).into()
+    blake3::derive_key(context, material)
 }
 
 /// BLAKE3 Extend.
 ///
 /// # Example
 /// ```
-/// let buffer:[u8;64] = [0u8;64];
+/// let mut buffer:[u8;64] = [0u8;64];
 ///
 /// xck::hash::blake3_xof(b"hello",&mut buffer);
 ///
-/// println!("{:?}",digest);
+/// println!("{:?}",buffer);
 /// ```
 pub fn blake3_xof(bytes: &[u8], dst: &mut [u8]) {
     Blake3::new().update(bytes).finalize_xof().fill(dst);
@@ -95,90 +113,979 @@ pub fn sha256(bytes: &[u8]) -> [u8; SIZE_32] {
     Sha256::digest(bytes).into()
 }
 
-// /// # WARNING
-// /// This is in the development and even PHC-string is not supported.
-// pub mod password_hash {
-
-//     pub use argon2;
-
-//     use crate::{size::SIZE_32, Error, Result};
-
-//     /// Argon2id
-//     ///
-//     /// Algorithm: Argon2id
-//     ///
-//     /// Version: 19 (0x13)
-//     ///
-//     /// Memory cost: 19456
-//     ///
-//     /// Time(number of iterations): 2
-//     ///
-//     /// Parallelism: 1
-//     ///
-//     /// Length: 32-byte
-//     ///
-//     /// # Example
-//     ///```
-//     /// let password = b"hello 0123 password";
-//     ///
-//     /// let salt = xck::rand::gen_16();
-//     ///
-//     /// let hash = xck::hash::password_hash::argon2id(password, &salt).unwrap();
-//     ///
-//     /// println!("{:?}",hash);
-//     ///```
-//     pub fn argon2id(password: impl AsRef<[u8]>, salt: impl AsRef<[u8]>) -> Result<[u8; SIZE_32]> {
-//         let mut buf = [0u8; SIZE_32];
-
-//         argon2::Argon2::default()
-//             .hash_password_into(password.as_ref(), salt.as_ref(), &mut buf)
-//             .map_err(|err| Error::new(err.to_string()))?;
-//         Ok(buf)
-//     }
-
-//     /// Argon2id With Secret
-//     ///
-//     /// Algorithm: Argon2id
-//     ///
-//     /// Version: 19 (0x13)
-//     ///
-//     /// Memory cost: 19456
-//     ///
-//     /// Time(number of iterations): 2
-//     ///
-//     /// Parallelism: 1
-//     ///
-//     /// Length: 32-byte
-//     ///
-//     /// # Example
-//     ///```
-//     /// let password = b"hello 0123 password";
-//     ///
-//     /// let salt = xck::rand::gen_16();
-//     ///
-//     /// let secret = xck::rand::gen_32();
-//     ///
-//     /// let hash = xck::hash::password_hash::argon2id_with_secret(password, &salt, &secret).unwrap();
-//     ///
-//     /// println!("{:?}",hash);
-//     ///```
-//     pub fn argon2id_with_secret(
-//         password: impl AsRef<[u8]>,
-//         salt: impl AsRef<[u8]>,
-//         secret: impl AsRef<[u8]>,
-//     ) -> Result<[u8; SIZE_32]> {
-//         let mut buf = [0u8; SIZE_32];
-
-//         argon2::Argon2::new_with_secret(
-//             secret.as_ref(),
-//             argon2::Algorithm::Argon2id,
-//             argon2::Version::V0x13,
-//             argon2::Params::default(),
-//         )
-//         .map_err(|err| Error::new(err.to_string()))?
-//         .hash_password_into(password.as_ref(), salt.as_ref(), &mut buf)
-//         .map_err(|err| Error::new(err.to_string()))?;
-
-//         Ok(buf)
-//     }
-// }
+/// BLAKE2 parameters.
+///
+/// BLAKE2b: key up to 64-byte, salt and personal up to 16-byte.
+///
+/// BLAKE2s: key up to 32-byte, salt and personal up to 8-byte.
+///
+/// Empty is the same as not set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Blake2Params<'a> {
+    pub key: &'a [u8],
+    pub salt: &'a [u8],
+    pub personal: &'a [u8],
+}
+
+impl Blake2Params<'_> {
+    fn check(&self, key_max: usize, salt_max: usize, len: usize, len_max: usize) -> Result<()> {
+        if self.key.len() > key_max {
+            Err(Error::new(
+                ErrorKind::InvalidKeyLength,
+                format!("the key length must be at most {key_max}-byte."),
+            ))?
+        }
+
+        if self.salt.len() > salt_max || self.personal.len() > salt_max {
+            Err(Error::new(
+                ErrorKind::InvalidLength,
+                format!("the salt and personal length must be at most {salt_max}-byte."),
+            ))?
+        }
+
+        if !(1..=len_max).contains(&len) {
+            Err(Error::new(
+                ErrorKind::InvalidLength,
+                format!("the output length must be 1 to {len_max}-byte."),
+            ))?
+        }
+
+        Ok(())
+    }
+
+    fn blake2b(&self, len: usize) -> Result<blake2b_simd::Params> {
+        self.check(SIZE_64, SIZE_16, len, SIZE_64)?;
+
+        let mut params = blake2b_simd::Params::new();
+
+        params
+            .hash_length(len)
+            .key(self.key)
+            .salt(self.salt)
+            .personal(self.personal);
+
+        Ok(params)
+    }
+
+    fn blake2s(&self, len: usize) -> Result<blake2s_simd::Params> {
+        self.check(SIZE_32, SIZE_8, len, SIZE_32)?;
+
+        let mut params = blake2s_simd::Params::new();
+
+        params
+            .hash_length(len)
+            .key(self.key)
+            .salt(self.salt)
+            .personal(self.personal);
+
+        Ok(params)
+    }
+}
+
+/// BLAKE2b-512 hash digest.
+///
+/// # Example
+/// ```
+/// let digest = xck::hash::blake2b(b"hello");
+///
+/// println!("{:?}",digest);
+/// ```
+pub fn blake2b(bytes: &[u8]) -> [u8; SIZE_64] {
+    blake2b_simd::blake2b(bytes).as_array().to_owned()
+}
+
+/// BLAKE2b with parameters. The output length is dst length, 1 to 64-byte.
+///
+/// Same as libsodium `crypto_generichash` with key only.
+///
+/// # Example
+/// ```
+/// let params = xck::hash::Blake2Params {
+///     key: b"key",
+///     personal: b"application",
+///     ..Default::default()
+/// };
+///
+/// let mut buffer = [0u8; 32];
+///
+/// xck::hash::blake2b_with_params(&params, b"hello", &mut buffer).unwrap();
+///
+/// println!("{:?}",buffer);
+/// ```
+pub fn blake2b_with_params(params: &Blake2Params, message: &[u8], dst: &mut [u8]) -> Result<()> {
+    dst.copy_from_slice(params.blake2b(dst.len())?.hash(message).as_bytes());
+
+    Ok(())
+}
+
+/// BLAKE2b Message authentication code. The output length is dst length, 1 to 64-byte.
+///
+/// key is up to 64-byte.
+pub fn blake2b_mac(key: &[u8], message: &[u8], dst: &mut [u8]) -> Result<()> {
+    blake2b_with_params(
+        &Blake2Params {
+            key,
+            ..Default::default()
+        },
+        message,
+        dst,
+    )
+}
+
+/// BLAKE2s-256 hash digest.
+///
+/// # Example
+/// ```
+/// let digest = xck::hash::blake2s(b"hello");
+///
+/// println!("{:?}",digest);
+/// ```
+pub fn blake2s(bytes: &[u8]) -> [u8; SIZE_32] {
+    blake2s_simd::blake2s(bytes).as_array().to_owned()
+}
+
+/// BLAKE2s with parameters. The output length is dst length, 1 to 32-byte.
+pub fn blake2s_with_params(params: &Blake2Params, message: &[u8], dst: &mut [u8]) -> Result<()> {
+    dst.copy_from_slice(params.blake2s(dst.len())?.hash(message).as_bytes());
+
+    Ok(())
+}
+
+/// BLAKE2s Message authentication code. The output length is dst length, 1 to 32-byte.
+///
+/// key is up to 32-byte.
+pub fn blake2s_mac(key: &[u8], message: &[u8], dst: &mut [u8]) -> Result<()> {
+    blake2s_with_params(
+        &Blake2Params {
+            key,
+            ..Default::default()
+        },
+        message,
+        dst,
+    )
+}
+
+/// SHA3-256 hash digest.
+///
+/// # Example
+/// ```
+/// let digest = xck::hash::sha3_256(b"hello");
+///
+/// println!("{:?}",digest);
+/// ```
+pub fn sha3_256(bytes: &[u8]) -> [u8; SIZE_32] {
+    Sha3_256::digest(bytes).into()
+}
+
+/// SHA3-512 hash digest.
+///
+/// # Example
+/// ```
+/// let digest = xck::hash::sha3_512(b"hello");
+///
+/// println!("{:?}",digest);
+/// ```
+pub fn sha3_512(bytes: &[u8]) -> [u8; SIZE_64] {
+    Sha3_512::digest(bytes).into()
+}
+
+/// Keccak-256 hash digest.
+///
+/// The original Keccak padding, as used by Ethereum. It differs from SHA3-256.
+///
+/// # Example
+/// ```
+/// let digest = xck::hash::keccak256(b"hello");
+///
+/// println!("{:?}",digest);
+/// ```
+pub fn keccak256(bytes: &[u8]) -> [u8; SIZE_32] {
+    Keccak256::digest(bytes).into()
+}
+
+/// SHAKE128 Extend.
+///
+/// # Example
+/// ```
+/// let mut buffer:[u8;64] = [0u8;64];
+///
+/// xck::hash::shake128(b"hello",&mut buffer);
+///
+/// println!("{:?}",buffer);
+/// ```
+pub fn shake128(bytes: &[u8], dst: &mut [u8]) {
+    let mut shake = Shake128::default();
+
+    sha3::digest::Update::update(&mut shake, bytes);
+
+    shake.finalize_xof().read(dst);
+}
+
+/// SHAKE256 Extend.
+///
+/// # Example
+/// ```
+/// let mut buffer:[u8;64] = [0u8;64];
+///
+/// xck::hash::shake256(b"hello",&mut buffer);
+///
+/// println!("{:?}",buffer);
+/// ```
+pub fn shake256(bytes: &[u8], dst: &mut [u8]) {
+    let mut shake = Shake256::default();
+
+    sha3::digest::Update::update(&mut shake, bytes);
+
+    shake.finalize_xof().read(dst);
+}
+
+/// HMAC-SHA256.
+///
+/// # Example
+/// ```
+/// let tag = xck::hash::hmac_sha256(b"key", b"message");
+///
+/// println!("{:?}",tag);
+/// ```
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; SIZE_32] {
+    let mut mac = <Hmac<Sha256>>::new_from_slice(key).unwrap();
+
+    mac.update(message);
+
+    mac.finalize().into_bytes().into()
+}
+
+/// HMAC-SHA256 Verify.
+///
+/// The comparison is constant time. Result does not return an error if the tag is correct. That is, `is_ok() == true`.
+///
+/// # Example
+/// ```
+/// let tag = xck::hash::hmac_sha256(b"key", b"message");
+///
+/// assert!(xck::hash::hmac_sha256_verify(b"key", b"message", &tag).is_ok());
+/// ```
+pub fn hmac_sha256_verify(key: &[u8], message: &[u8], tag: &[u8]) -> Result<()> {
+    let mut mac = <Hmac<Sha256>>::new_from_slice(key).unwrap();
+
+    mac.update(message);
+
+    mac.verify_slice(tag)
+        .map_err(|err| Error::with_source(ErrorKind::AuthenticationFailed, err))
+}
+
+/// HMAC-SHA512.
+///
+/// # Example
+/// ```
+/// let tag = xck::hash::hmac_sha512(b"key", b"message");
+///
+/// println!("{:?}",tag);
+/// ```
+pub fn hmac_sha512(key: &[u8], message: &[u8]) -> [u8; SIZE_64] {
+    let mut mac = <Hmac<Sha512>>::new_from_slice(key).unwrap();
+
+    mac.update(message);
+
+    mac.finalize().into_bytes().into()
+}
+
+/// HMAC-SHA512 Verify.
+///
+/// The comparison is constant time. Result does not return an error if the tag is correct. That is, `is_ok() == true`.
+pub fn hmac_sha512_verify(key: &[u8], message: &[u8], tag: &[u8]) -> Result<()> {
+    let mut mac = <Hmac<Sha512>>::new_from_slice(key).unwrap();
+
+    mac.update(message);
+
+    mac.verify_slice(tag)
+        .map_err(|err| Error::with_source(ErrorKind::AuthenticationFailed, err))
+}
+
+/// HKDF-SHA256 Extract (RFC 5869).
+///
+/// Returns the pseudorandom key. An empty salt is the same as 32 zero bytes.
+pub fn hkdf_sha256_extract(salt: &[u8], ikm: &[u8]) -> [u8; SIZE_32] {
+    hkdf::Hkdf::<Sha256>::extract(Some(salt), ikm).0.into()
+}
+
+/// HKDF-SHA256 Expand (RFC 5869).
+///
+/// Fills okm. prk must be at least 32-byte and okm at most 8160-byte.
+pub fn hkdf_sha256_expand(prk: &[u8], info: &[u8], okm: &mut [u8]) -> Result<()> {
+    hkdf::Hkdf::<Sha256>::from_prk(prk)
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidKeyLength,
+                "the prk length must be at least 32-byte.".to_owned(),
+            )
+        })?
+        .expand(info, okm)
+        .map_err(|err| Error::with_source(ErrorKind::InvalidLength, err))
+}
+
+/// HKDF-SHA256 (RFC 5869). Extract and expand.
+///
+/// # Example
+/// ```
+/// let mut key = [0u8; 32];
+///
+/// xck::hash::hkdf_sha256(b"salt", b"input key material", b"info", &mut key).unwrap();
+///
+/// println!("{:?}",key);
+/// ```
+pub fn hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) -> Result<()> {
+    hkdf::Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(info, okm)
+        .map_err(|err| Error::with_source(ErrorKind::InvalidLength, err))
+}
+
+/// HKDF-SHA512 Extract (RFC 5869).
+///
+/// Returns the pseudorandom key. An empty salt is the same as 64 zero bytes.
+pub fn hkdf_sha512_extract(salt: &[u8], ikm: &[u8]) -> [u8; SIZE_64] {
+    hkdf::Hkdf::<Sha512>::extract(Some(salt), ikm).0.into()
+}
+
+/// HKDF-SHA512 Expand (RFC 5869).
+///
+/// Fills okm. prk must be at least 64-byte and okm at most 16320-byte.
+pub fn hkdf_sha512_expand(prk: &[u8], info: &[u8], okm: &mut [u8]) -> Result<()> {
+    hkdf::Hkdf::<Sha512>::from_prk(prk)
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidKeyLength,
+                "the prk length must be at least 64-byte.".to_owned(),
+            )
+        })?
+        .expand(info, okm)
+        .map_err(|err| Error::with_source(ErrorKind::InvalidLength, err))
+}
+
+/// HKDF-SHA512 (RFC 5869). Extract and expand.
+pub fn hkdf_sha512(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) -> Result<()> {
+    hkdf::Hkdf::<Sha512>::new(Some(salt), ikm)
+        .expand(info, okm)
+        .map_err(|err| Error::with_source(ErrorKind::InvalidLength, err))
+}
+
+/// Hash algorithms with an incremental hasher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha512,
+    Sha512_256,
+    Sha3_256,
+    Sha3_512,
+    Keccak256,
+    Blake2b,
+    Blake2s,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    /// All algorithms.
+    pub const ALL: [Self; 9] = [
+        Self::Sha256,
+        Self::Sha512,
+        Self::Sha512_256,
+        Self::Sha3_256,
+        Self::Sha3_512,
+        Self::Keccak256,
+        Self::Blake2b,
+        Self::Blake2s,
+        Self::Blake3,
+    ];
+
+    /// Name of the algorithm.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha512 => "sha512",
+            Self::Sha512_256 => "sha512/256",
+            Self::Sha3_256 => "sha3-256",
+            Self::Sha3_512 => "sha3-512",
+            Self::Keccak256 => "keccak256",
+            Self::Blake2b => "blake2b",
+            Self::Blake2s => "blake2s",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    /// Digest length in bytes.
+    pub fn output_len(&self) -> usize {
+        match self {
+            Self::Sha512 | Self::Sha3_512 | Self::Blake2b => SIZE_64,
+            _ => SIZE_32,
+        }
+    }
+
+    /// A new hasher of the algorithm.
+    #[cfg(feature = "alloc")]
+    pub fn hasher(&self) -> Box<dyn Hasher> {
+        match self {
+            Self::Sha256 => Box::new(Sha256Hasher::new()),
+            Self::Sha512 => Box::new(Sha512Hasher::new()),
+            Self::Sha512_256 => Box::new(Sha512_256Hasher::new()),
+            Self::Sha3_256 => Box::new(Sha3_256Hasher::new()),
+            Self::Sha3_512 => Box::new(Sha3_512Hasher::new()),
+            Self::Keccak256 => Box::new(Keccak256Hasher::new()),
+            Self::Blake2b => Box::new(Blake2bHasher::new()),
+            Self::Blake2s => Box::new(Blake2sHasher::new()),
+            Self::Blake3 => Box::new(Blake3Hasher::new()),
+        }
+    }
+}
+
+impl core::str::FromStr for HashAlgorithm {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+            .ok_or(Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported hash algorithm: {name}."),
+            ))
+    }
+}
+
+impl core::fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Incremental hasher.
+///
+/// Every hasher in this module implements it, so a stream can be hashed without knowing the algorithm in advance. Bytes written through `std::io::Write` are absorbed like `update`.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+///
+/// let mut hasher = xck::hash::Sha256Hasher::new();
+///
+/// hasher.write_all(b"hel").unwrap();
+///
+/// hasher.write_all(b"lo").unwrap();
+///
+/// assert_eq!(hasher.finalize(), xck::hash::sha256(b"hello"));
+/// ```
+pub trait Hasher: Write {
+    /// Digest length in bytes.
+    fn output_len(&self) -> usize;
+
+    /// Absorb bytes.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Write the digest to the head of dst and return its length. The state is left as it is.
+    fn finalize_into(&self, dst: &mut [u8]) -> Result<usize>;
+
+    /// Reset to the initial state. A key or context is kept.
+    fn reset(&mut self);
+}
+
+/// Digest of everything from reader.
+///
+/// # Example
+/// ```
+/// let digest = xck::hash::hash_reader(xck::hash::HashAlgorithm::Sha256, &mut &b"hello"[..]).unwrap();
+///
+/// assert_eq!(digest, xck::hash::sha256(b"hello"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hash_reader(algorithm: HashAlgorithm, reader: &mut impl io::Read) -> Result<Vec<u8>> {
+    let mut hasher = algorithm.hasher();
+
+    io::copy(reader, &mut hasher)?;
+
+    let mut digest = alloc::vec![0u8; hasher.output_len()];
+
+    hasher.finalize_into(&mut digest)?;
+
+    Ok(digest)
+}
+
+fn finalize_into(digest: &[u8], dst: &mut [u8]) -> Result<usize> {
+    if dst.len() < digest.len() {
+        Err(Error::new(
+            ErrorKind::BufferTooSmall,
+            format!("the buffer must be at least {}-byte.", digest.len()),
+        ))?
+    }
+
+    dst[..digest.len()].copy_from_slice(digest);
+
+    Ok(digest.len())
+}
+
+macro_rules! digest_hasher {
+    ($(#[$meta:meta])* $name:ident, $inner:ident, $size:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Default)]
+        pub struct $name($inner);
+
+        impl $name {
+            pub fn new() -> Self {
+                Self($inner::new())
+            }
+
+            pub fn update(&mut self, bytes: &[u8]) {
+                Digest::update(&mut self.0, bytes);
+            }
+
+            pub fn finalize(&self) -> [u8; $size] {
+                self.0.clone().finalize().into()
+            }
+
+            pub fn reset(&mut self) {
+                Digest::reset(&mut self.0);
+            }
+        }
+
+        impl Hasher for $name {
+            fn output_len(&self) -> usize {
+                $size
+            }
+
+            fn update(&mut self, bytes: &[u8]) {
+                $name::update(self, bytes);
+            }
+
+            fn finalize_into(&self, dst: &mut [u8]) -> Result<usize> {
+                finalize_into(&self.finalize(), dst)
+            }
+
+            fn reset(&mut self) {
+                $name::reset(self);
+            }
+        }
+
+        impl Write for $name {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.update(buf);
+
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+digest_hasher!(
+    /// SHA256 incremental hasher.
+    Sha256Hasher,
+    Sha256,
+    SIZE_32
+);
+
+digest_hasher!(
+    /// SHA512 incremental hasher.
+    Sha512Hasher,
+    Sha512,
+    SIZE_64
+);
+
+digest_hasher!(
+    /// SHA512/256 incremental hasher.
+    Sha512_256Hasher,
+    Sha512_256,
+    SIZE_32
+);
+
+digest_hasher!(
+    /// SHA3-256 incremental hasher.
+    Sha3_256Hasher,
+    Sha3_256,
+    SIZE_32
+);
+
+digest_hasher!(
+    /// SHA3-512 incremental hasher.
+    Sha3_512Hasher,
+    Sha3_512,
+    SIZE_64
+);
+
+digest_hasher!(
+    /// Keccak-256 incremental hasher.
+    Keccak256Hasher,
+    Keccak256,
+    SIZE_32
+);
+
+macro_rules! blake2_hasher {
+    ($(#[$meta:meta])* $name:ident, $module:ident, $params:ident) => {
+        $(#[$meta])*
+        #[derive(Clone)]
+        pub struct $name {
+            params: $module::Params,
+            state: $module::State,
+            len: usize,
+        }
+
+        impl $name {
+            /// Default output length and no key, salt or personal.
+            pub fn new() -> Self {
+                let params = $module::Params::new();
+
+                Self {
+                    state: params.to_state(),
+                    params,
+                    len: $module::OUTBYTES,
+                }
+            }
+
+            /// With parameters and output length.
+            pub fn with_params(params: &Blake2Params, len: usize) -> Result<Self> {
+                let params = params.$params(len)?;
+
+                Ok(Self {
+                    state: params.to_state(),
+                    params,
+                    len,
+                })
+            }
+
+            pub fn update(&mut self, bytes: &[u8]) {
+                self.state.update(bytes);
+            }
+
+            pub fn reset(&mut self) {
+                self.state = self.params.to_state();
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Hasher for $name {
+            fn output_len(&self) -> usize {
+                self.len
+            }
+
+            fn update(&mut self, bytes: &[u8]) {
+                $name::update(self, bytes);
+            }
+
+            fn finalize_into(&self, dst: &mut [u8]) -> Result<usize> {
+                finalize_into(self.state.finalize().as_bytes(), dst)
+            }
+
+            fn reset(&mut self) {
+                $name::reset(self);
+            }
+        }
+
+        impl Write for $name {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.update(buf);
+
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+blake2_hasher!(
+    /// BLAKE2b incremental hasher.
+    Blake2bHasher,
+    blake2b_simd,
+    blake2b
+);
+
+blake2_hasher!(
+    /// BLAKE2s incremental hasher.
+    Blake2sHasher,
+    blake2s_simd,
+    blake2s
+);
+
+/// BLAKE3 incremental hasher.
+///
+/// Regular hash, keyed hash (MAC), key derivation and XOF.
+///
+/// # Example
+/// ```
+/// let mut hasher = xck::hash::Blake3Hasher::new_keyed(&[1u8; 32]);
+///
+/// hasher.update(b"mess");
+///
+/// hasher.update(b"age");
+///
+/// assert_eq!(hasher.finalize(), xck::hash::blake3_mac(&[1u8; 32], b"message"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Blake3Hasher(Blake3);
+
+impl Blake3Hasher {
+    /// Regular hash.
+    pub fn new() -> Self {
+        Self(Blake3::new())
+    }
+
+    /// Keyed hash. Same as `blake3_mac`.
+    pub fn new_keyed(key: &[u8; SIZE_32]) -> Self {
+        Self(Blake3::new_keyed(key))
+    }
+
+    /// Key derivation. Same as `blake3_kdf`, the key material is absorbed with `update`.
+    pub fn new_derive_key(context: &str) -> Self {
+        Self(Blake3::new_derive_key(context))
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    pub fn finalize(&self) -> [u8; SIZE_32] {
+        self.0.finalize().into()
+    }
+
+    /// Extend output. Same as `blake3_xof`.
+    pub fn finalize_xof(&self, dst: &mut [u8]) {
+        self.0.finalize_xof().fill(dst);
+    }
+
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Hasher for Blake3Hasher {
+    fn output_len(&self) -> usize {
+        SIZE_32
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        Blake3Hasher::update(self, bytes);
+    }
+
+    fn finalize_into(&self, dst: &mut [u8]) -> Result<usize> {
+        finalize_into(&self.finalize(), dst)
+    }
+
+    fn reset(&mut self) {
+        Blake3Hasher::reset(self);
+    }
+}
+
+impl Write for Blake3Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Argon2 password hashing.
+///
+/// PHC string format: `$argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>`
+pub mod password_hash {
+
+    pub use argon2;
+
+    use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+
+    use crate::{size::SIZE_32, Error, ErrorKind, Result};
+
+    /// Default memory cost in KiB. 19456
+    pub const DEFAULT_MEMORY_COST: u32 = argon2::Params::DEFAULT_M_COST;
+
+    /// Default time cost (number of iterations). 2
+    pub const DEFAULT_TIME_COST: u32 = argon2::Params::DEFAULT_T_COST;
+
+    /// Default parallelism. 1
+    pub const DEFAULT_PARALLELISM: u32 = argon2::Params::DEFAULT_P_COST;
+
+    /// Argon2id
+    ///
+    /// Algorithm: Argon2id
+    ///
+    /// Version: 19 (0x13)
+    ///
+    /// Memory cost: 19456
+    ///
+    /// Time(number of iterations): 2
+    ///
+    /// Parallelism: 1
+    ///
+    /// Length: 32-byte
+    ///
+    /// # Example
+    ///```
+    /// let password = b"hello 0123 password";
+    ///
+    /// let salt = xck::rand::gen_16();
+    ///
+    /// let hash = xck::hash::password_hash::argon2id(password, &salt).unwrap();
+    ///
+    /// println!("{:?}",hash);
+    ///```
+    pub fn argon2id(password: impl AsRef<[u8]>, salt: impl AsRef<[u8]>) -> Result<[u8; SIZE_32]> {
+        let mut buf = [0u8; SIZE_32];
+
+        argon2::Argon2::default()
+            .hash_password_into(password.as_ref(), salt.as_ref(), &mut buf)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?;
+        Ok(buf)
+    }
+
+    /// Argon2id With Secret
+    ///
+    /// Algorithm: Argon2id
+    ///
+    /// Version: 19 (0x13)
+    ///
+    /// Memory cost: 19456
+    ///
+    /// Time(number of iterations): 2
+    ///
+    /// Parallelism: 1
+    ///
+    /// Length: 32-byte
+    ///
+    /// # Example
+    ///```
+    /// let password = b"hello 0123 password";
+    ///
+    /// let salt = xck::rand::gen_16();
+    ///
+    /// let secret = xck::rand::gen_32();
+    ///
+    /// let hash = xck::hash::password_hash::argon2id_with_secret(password, &salt, &secret).unwrap();
+    ///
+    /// println!("{:?}",hash);
+    ///```
+    pub fn argon2id_with_secret(
+        password: impl AsRef<[u8]>,
+        salt: impl AsRef<[u8]>,
+        secret: impl AsRef<[u8]>,
+    ) -> Result<[u8; SIZE_32]> {
+        let mut buf = [0u8; SIZE_32];
+
+        argon2::Argon2::new_with_secret(
+            secret.as_ref(),
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            argon2::Params::default(),
+        )
+        .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?
+        .hash_password_into(password.as_ref(), salt.as_ref(), &mut buf)
+        .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?;
+
+        Ok(buf)
+    }
+
+    /// Argon2id PHC string.
+    ///
+    /// Hashes password with the default parameters and a random 16-byte salt.
+    ///
+    /// # Example
+    ///```
+    /// let phc = xck::hash::password_hash::hash_password(b"hello 0123 password").unwrap();
+    ///
+    /// println!("{phc}");
+    ///```
+    pub fn hash_password(password: impl AsRef<[u8]>) -> Result<String> {
+        hash_password_with_params(
+            password,
+            DEFAULT_MEMORY_COST,
+            DEFAULT_TIME_COST,
+            DEFAULT_PARALLELISM,
+        )
+    }
+
+    /// Argon2id PHC string with parameters.
+    ///
+    /// Memory cost is in KiB.
+    pub fn hash_password_with_params(
+        password: impl AsRef<[u8]>,
+        memory_cost: u32,
+        time_cost: u32,
+        parallelism: u32,
+    ) -> Result<String> {
+        let params = argon2::Params::new(memory_cost, time_cost, parallelism, None)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?;
+
+        let salt = SaltString::encode_b64(&crate::rand::gen_16())
+            .map_err(|err| Error::with_source(ErrorKind::Other, err))?;
+
+        let phc = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+            .hash_password(password.as_ref(), &salt)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?
+            .to_string();
+
+        Ok(phc)
+    }
+
+    /// Verify password against a PHC string.
+    ///
+    /// Argon2d, Argon2i and Argon2id are supported, with the parameters in the PHC string. The comparison is constant time.
+    ///
+    /// Result does not return an error if the authentication is successful. That is, `is_ok() == true`.
+    ///
+    /// # Example
+    ///```
+    /// let phc = xck::hash::password_hash::hash_password(b"hello 0123 password").unwrap();
+    ///
+    /// let is_ok = xck::hash::password_hash::verify_password(b"hello 0123 password", &phc).is_ok();
+    ///
+    /// println!("{is_ok}");
+    ///```
+    pub fn verify_password(password: impl AsRef<[u8]>, phc: &str) -> Result<()> {
+        let hash = PasswordHash::new(phc)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?;
+
+        argon2::Argon2::default()
+            .verify_password(password.as_ref(), &hash)
+            .map_err(|err| match err {
+                argon2::password_hash::Error::Password => {
+                    Error::with_source(ErrorKind::AuthenticationFailed, err)
+                }
+                _ => Error::with_source(ErrorKind::InvalidEncoding, err),
+            })
+    }
+
+    /// Whether a PHC string should be rehashed.
+    ///
+    /// Returns true if it is not Argon2id version 19, or its memory cost, time cost or parallelism differ from the given ones.
+    pub fn needs_rehash(
+        phc: &str,
+        memory_cost: u32,
+        time_cost: u32,
+        parallelism: u32,
+    ) -> Result<bool> {
+        let hash = PasswordHash::new(phc)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?;
+
+        if hash.algorithm != argon2::Algorithm::Argon2id.ident()
+            || hash.version != Some(argon2::Version::V0x13.into())
+        {
+            return Ok(true);
+        }
+
+        let params = argon2::Params::try_from(&hash)
+            .map_err(|err| Error::with_source(ErrorKind::InvalidParameter, err))?;
+
+        Ok(params.m_cost() != memory_cost
+            || params.t_cost() != time_cost
+            || params.p_cost() != parallelism)
+    }
+}
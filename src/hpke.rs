@@ -0,0 +1,529 @@
+//! HPKE, Hybrid Public Key Encryption. RFC 9180.
+//!
+//! KEM: DHKEM(X25519, HKDF-SHA256). KDF: HKDF-SHA256.
+//!
+//! AEAD: AES-128-GCM, AES-256-GCM, ChaCha20-Poly1305.
+//!
+//! Modes: base and auth.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    hash::{hkdf_sha256_expand, hkdf_sha256_extract},
+    size::SIZE_32,
+    symmetric,
+    types::{X25519PublicKey, X25519SecretKey},
+    Error, ErrorKind, Result,
+};
+
+pub use crate::symmetric::AeadAlgorithm as Algorithm;
+
+/// DHKEM(X25519, HKDF-SHA256) id.
+pub const KEM_ID: u16 = 0x0020;
+
+/// HKDF-SHA256 id.
+pub const KDF_ID: u16 = 0x0001;
+
+/// Base mode id.
+pub const MODE_BASE: u8 = 0x00;
+
+/// Auth mode id.
+pub const MODE_AUTH: u8 = 0x02;
+
+const VERSION_LABEL: &[u8] = b"HPKE-v1";
+
+// HKDF-SHA256 output size.
+const NH: usize = SIZE_32;
+
+/// AEAD id of algorithm.
+///
+/// XChaCha20-Poly1305 and AES-192-GCM are not HPKE AEADs and return an Unsupported error.
+pub fn aead_id(algorithm: Algorithm) -> Result<u16> {
+    match algorithm {
+        Algorithm::Aes128Gcm => Ok(0x0001),
+        Algorithm::Aes256Gcm => Ok(0x0002),
+        Algorithm::ChaCha20Poly1305 => Ok(0x0003),
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("unsupported hpke aead algorithm: {algorithm}."),
+        )),
+    }
+}
+
+fn kem_suite_id() -> Vec<u8> {
+    [b"KEM".as_slice(), &KEM_ID.to_be_bytes()].concat()
+}
+
+fn hpke_suite_id(algorithm: Algorithm) -> Result<Vec<u8>> {
+    Ok([
+        b"HPKE".as_slice(),
+        &KEM_ID.to_be_bytes(),
+        &KDF_ID.to_be_bytes(),
+        &aead_id(algorithm)?.to_be_bytes(),
+    ]
+    .concat())
+}
+
+fn labeled_extract(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> [u8; NH] {
+    let labeled_ikm = Zeroizing::new([VERSION_LABEL, suite_id, label, ikm].concat());
+
+    hkdf_sha256_extract(salt, &labeled_ikm)
+}
+
+fn labeled_expand(
+    suite_id: &[u8],
+    prk: &[u8],
+    label: &[u8],
+    info: &[u8],
+    len: usize,
+) -> Result<Vec<u8>> {
+    let len_bytes = u16::try_from(len)
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidLength,
+                "the output length is too long.".to_owned(),
+            )
+        })?
+        .to_be_bytes();
+
+    let labeled_info = [&len_bytes, VERSION_LABEL, suite_id, label, info].concat();
+
+    let mut okm = alloc::vec![0u8; len];
+
+    hkdf_sha256_expand(prk, &labeled_info, &mut okm)?;
+
+    Ok(okm)
+}
+
+/// DeriveKeyPair. Derives an X25519 keypair from ikm deterministically.
+///
+/// # Example
+/// ```
+/// let (private_key, public_key) = xck::hpke::derive_keypair(&xck::rand::gen_32());
+///
+/// println!("{:?}\n{:?}",private_key,public_key);
+/// ```
+pub fn derive_keypair(ikm: &[u8]) -> (X25519SecretKey, X25519PublicKey) {
+    let suite_id = kem_suite_id();
+
+    let mut dkp_prk = labeled_extract(&suite_id, &[], b"dkp_prk", ikm);
+
+    // The length is fixed, expand does not fail.
+    let sk = Zeroizing::new(labeled_expand(&suite_id, &dkp_prk, b"sk", &[], SIZE_32).unwrap());
+
+    dkp_prk.zeroize();
+
+    let private_key = X25519SecretKey::try_from(sk.as_slice()).unwrap();
+
+    let public_key = crate::asymmetric::x25519_gen_public_key(&private_key);
+
+    (private_key, public_key)
+}
+
+fn dh(
+    private_key: &X25519SecretKey,
+    public_key: &X25519PublicKey,
+) -> Result<Zeroizing<[u8; SIZE_32]>> {
+    let shared_secret = x25519_dalek::StaticSecret::from(private_key.as_bytes().to_owned())
+        .diffie_hellman(&x25519_dalek::PublicKey::from(public_key.to_bytes()));
+
+    if !shared_secret.was_contributory() {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "the public-key is a low order point.".to_owned(),
+        ))?
+    }
+
+    Ok(Zeroizing::new(shared_secret.to_bytes()))
+}
+
+fn extract_and_expand(dh: &[u8], kem_context: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+    let suite_id = kem_suite_id();
+
+    let mut eae_prk = labeled_extract(&suite_id, &[], b"eae_prk", dh);
+
+    let shared_secret = labeled_expand(&suite_id, &eae_prk, b"shared_secret", kem_context, NH);
+
+    eae_prk.zeroize();
+
+    Ok(Zeroizing::new(shared_secret?))
+}
+
+// Encap and AuthEncap. sender is (skS, pkS) for auth mode.
+fn encap(
+    ephemeral_private_key: &X25519SecretKey,
+    recipient: &X25519PublicKey,
+    sender: Option<&X25519SecretKey>,
+) -> Result<(Zeroizing<Vec<u8>>, X25519PublicKey)> {
+    let enc = crate::asymmetric::x25519_gen_public_key(ephemeral_private_key);
+
+    let mut dh_bytes = Zeroizing::new(dh(ephemeral_private_key, recipient)?.to_vec());
+
+    let mut kem_context = [enc.as_bytes().as_slice(), recipient.as_bytes()].concat();
+
+    if let Some(sender) = sender {
+        dh_bytes.extend_from_slice(dh(sender, recipient)?.as_slice());
+
+        kem_context.extend_from_slice(crate::asymmetric::x25519_gen_public_key(sender).as_bytes());
+    }
+
+    Ok((extract_and_expand(&dh_bytes, &kem_context)?, enc))
+}
+
+// Decap and AuthDecap. sender is pkS for auth mode.
+fn decap(
+    enc: &X25519PublicKey,
+    private_key: &X25519SecretKey,
+    sender: Option<&X25519PublicKey>,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let mut dh_bytes = Zeroizing::new(dh(private_key, enc)?.to_vec());
+
+    let mut kem_context = [
+        enc.as_bytes().as_slice(),
+        crate::asymmetric::x25519_gen_public_key(private_key).as_bytes(),
+    ]
+    .concat();
+
+    if let Some(sender) = sender {
+        dh_bytes.extend_from_slice(dh(private_key, sender)?.as_slice());
+
+        kem_context.extend_from_slice(sender.as_bytes());
+    }
+
+    extract_and_expand(&dh_bytes, &kem_context)
+}
+
+fn key_schedule(
+    algorithm: Algorithm,
+    mode: u8,
+    shared_secret: &[u8],
+    info: &[u8],
+) -> Result<Context> {
+    let suite_id = hpke_suite_id(algorithm)?;
+
+    // psk and psk_id are empty in base and auth modes.
+    let psk_id_hash = labeled_extract(&suite_id, &[], b"psk_id_hash", &[]);
+
+    let info_hash = labeled_extract(&suite_id, &[], b"info_hash", info);
+
+    let key_schedule_context = [[mode].as_slice(), &psk_id_hash, &info_hash].concat();
+
+    let mut secret = labeled_extract(&suite_id, shared_secret, b"secret", &[]);
+
+    let context = Context {
+        algorithm,
+        key: labeled_expand(
+            &suite_id,
+            &secret,
+            b"key",
+            &key_schedule_context,
+            algorithm.key_len(),
+        )?,
+        base_nonce: labeled_expand(
+            &suite_id,
+            &secret,
+            b"base_nonce",
+            &key_schedule_context,
+            algorithm.nonce_len(),
+        )?,
+        exporter_secret: labeled_expand(&suite_id, &secret, b"exp", &key_schedule_context, NH)?,
+        suite_id,
+        sequence: 0,
+    };
+
+    secret.zeroize();
+
+    Ok(context)
+}
+
+struct Context {
+    algorithm: Algorithm,
+    suite_id: Vec<u8>,
+    key: Vec<u8>,
+    base_nonce: Vec<u8>,
+    exporter_secret: Vec<u8>,
+    sequence: u64,
+}
+
+impl Context {
+    fn nonce(&self) -> Vec<u8> {
+        let mut nonce = self.base_nonce.clone();
+
+        let sequence = self.sequence.to_be_bytes();
+
+        let offset = nonce.len() - sequence.len();
+
+        for (byte, seq) in nonce[offset..].iter_mut().zip(sequence) {
+            *byte ^= seq;
+        }
+
+        nonce
+    }
+
+    fn increment(&mut self) -> Result<()> {
+        self.sequence = self.sequence.checked_add(1).ok_or(Error::new(
+            ErrorKind::InvalidParameter,
+            "the message limit is reached.".to_owned(),
+        ))?;
+
+        Ok(())
+    }
+
+    fn seal(&mut self, aad: &[u8], plain: &[u8]) -> Result<Vec<u8>> {
+        let cipher =
+            symmetric::encrypt_alloc(self.algorithm, &self.key, &self.nonce(), aad, plain)?;
+
+        self.increment()?;
+
+        Ok(cipher)
+    }
+
+    fn open(&mut self, aad: &[u8], cipher: &[u8]) -> Result<Vec<u8>> {
+        let plain =
+            symmetric::decrypt_alloc(self.algorithm, &self.key, &self.nonce(), aad, cipher)?;
+
+        self.increment()?;
+
+        Ok(plain)
+    }
+
+    fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>> {
+        labeled_expand(
+            &self.suite_id,
+            &self.exporter_secret,
+            b"sec",
+            exporter_context,
+            len,
+        )
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.base_nonce.zeroize();
+        self.exporter_secret.zeroize();
+    }
+}
+
+/// Sender context. Returned by `setup_base_s` and `setup_auth_s`.
+pub struct SenderContext(Context);
+
+impl SenderContext {
+    /// Seal. Encrypts plain and increments the sequence number.
+    ///
+    /// If you want Aad to be empty, use &[].
+    pub fn seal(&mut self, aad: &[u8], plain: &[u8]) -> Result<Vec<u8>> {
+        self.0.seal(aad, plain)
+    }
+
+    /// Export a secret of len bytes. len is up to 255 * 32 bytes.
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>> {
+        self.0.export(exporter_context, len)
+    }
+}
+
+/// Recipient context. Returned by `setup_base_r` and `setup_auth_r`.
+pub struct ReceiverContext(Context);
+
+impl ReceiverContext {
+    /// Open. Decrypts cipher and increments the sequence number.
+    pub fn open(&mut self, aad: &[u8], cipher: &[u8]) -> Result<Vec<u8>> {
+        self.0.open(aad, cipher)
+    }
+
+    /// Export a secret of len bytes. len is up to 255 * 32 bytes.
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>> {
+        self.0.export(exporter_context, len)
+    }
+}
+
+/// SetupBaseS.
+///
+/// Returns the encapsulated key (enc) and the sender context.
+///
+/// # Example
+/// ```
+/// let (private_key, public_key) = xck::asymmetric::x25519_gen_keypair();
+///
+/// let (enc, mut sender) = xck::hpke::setup_base_s(xck::hpke::Algorithm::ChaCha20Poly1305, &public_key, b"info").unwrap();
+///
+/// let cipher = sender.seal(&[], b"hello").unwrap();
+///
+/// let mut receiver = xck::hpke::setup_base_r(xck::hpke::Algorithm::ChaCha20Poly1305, &enc, &private_key, b"info").unwrap();
+///
+/// let plain = receiver.open(&[], &cipher).unwrap();
+///
+/// println!("{:?}",plain);
+/// ```
+pub fn setup_base_s(
+    algorithm: Algorithm,
+    recipient: &X25519PublicKey,
+    info: &[u8],
+) -> Result<(X25519PublicKey, SenderContext)> {
+    setup_base_s_with_ephemeral_key(
+        algorithm,
+        recipient,
+        info,
+        &crate::asymmetric::x25519_gen_private_key(),
+    )
+}
+
+/// SetupBaseS with a fixed ephemeral private-key (skE), for the RFC 9180 test vectors.
+///
+/// Do not use it otherwise: an ephemeral key must never be reused.
+#[doc(hidden)]
+pub fn setup_base_s_with_ephemeral_key(
+    algorithm: Algorithm,
+    recipient: &X25519PublicKey,
+    info: &[u8],
+    ephemeral_private_key: &X25519SecretKey,
+) -> Result<(X25519PublicKey, SenderContext)> {
+    let (shared_secret, enc) = encap(ephemeral_private_key, recipient, None)?;
+
+    let context = key_schedule(algorithm, MODE_BASE, &shared_secret, info)?;
+
+    Ok((enc, SenderContext(context)))
+}
+
+/// SetupBaseR.
+pub fn setup_base_r(
+    algorithm: Algorithm,
+    enc: &X25519PublicKey,
+    private_key: &X25519SecretKey,
+    info: &[u8],
+) -> Result<ReceiverContext> {
+    let shared_secret = decap(enc, private_key, None)?;
+
+    Ok(ReceiverContext(key_schedule(
+        algorithm,
+        MODE_BASE,
+        &shared_secret,
+        info,
+    )?))
+}
+
+/// SetupAuthS. The recipient can authenticate that the sender had sender_private_key.
+///
+/// Returns the encapsulated key (enc) and the sender context.
+pub fn setup_auth_s(
+    algorithm: Algorithm,
+    recipient: &X25519PublicKey,
+    info: &[u8],
+    sender_private_key: &X25519SecretKey,
+) -> Result<(X25519PublicKey, SenderContext)> {
+    setup_auth_s_with_ephemeral_key(
+        algorithm,
+        recipient,
+        info,
+        sender_private_key,
+        &crate::asymmetric::x25519_gen_private_key(),
+    )
+}
+
+/// SetupAuthS with a fixed ephemeral private-key (skE), for the RFC 9180 test vectors.
+///
+/// Do not use it otherwise: an ephemeral key must never be reused.
+#[doc(hidden)]
+pub fn setup_auth_s_with_ephemeral_key(
+    algorithm: Algorithm,
+    recipient: &X25519PublicKey,
+    info: &[u8],
+    sender_private_key: &X25519SecretKey,
+    ephemeral_private_key: &X25519SecretKey,
+) -> Result<(X25519PublicKey, SenderContext)> {
+    let (shared_secret, enc) = encap(ephemeral_private_key, recipient, Some(sender_private_key))?;
+
+    let context = key_schedule(algorithm, MODE_AUTH, &shared_secret, info)?;
+
+    Ok((enc, SenderContext(context)))
+}
+
+/// SetupAuthR.
+pub fn setup_auth_r(
+    algorithm: Algorithm,
+    enc: &X25519PublicKey,
+    private_key: &X25519SecretKey,
+    info: &[u8],
+    sender_public_key: &X25519PublicKey,
+) -> Result<ReceiverContext> {
+    let shared_secret = decap(enc, private_key, Some(sender_public_key))?;
+
+    Ok(ReceiverContext(key_schedule(
+        algorithm,
+        MODE_AUTH,
+        &shared_secret,
+        info,
+    )?))
+}
+
+/// SealBase. Single-shot encryption.
+///
+/// Returns the encapsulated key (enc) and the cipher.
+///
+/// # Example
+/// ```
+/// let (private_key, public_key) = xck::asymmetric::x25519_gen_keypair();
+///
+/// let (enc, cipher) = xck::hpke::seal_base(xck::hpke::Algorithm::Aes128Gcm, &public_key, b"info", &[], b"hello").unwrap();
+///
+/// let plain = xck::hpke::open_base(xck::hpke::Algorithm::Aes128Gcm, &enc, &private_key, b"info", &[], &cipher).unwrap();
+///
+/// println!("{:?}",plain);
+/// ```
+pub fn seal_base(
+    algorithm: Algorithm,
+    recipient: &X25519PublicKey,
+    info: &[u8],
+    aad: &[u8],
+    plain: &[u8],
+) -> Result<(X25519PublicKey, Vec<u8>)> {
+    let (enc, mut context) = setup_base_s(algorithm, recipient, info)?;
+
+    Ok((enc, context.seal(aad, plain)?))
+}
+
+/// OpenBase. Single-shot decryption.
+pub fn open_base(
+    algorithm: Algorithm,
+    enc: &X25519PublicKey,
+    private_key: &X25519SecretKey,
+    info: &[u8],
+    aad: &[u8],
+    cipher: &[u8],
+) -> Result<Vec<u8>> {
+    setup_base_r(algorithm, enc, private_key, info)?.open(aad, cipher)
+}
+
+/// SealAuth. Single-shot authenticated encryption.
+///
+/// Returns the encapsulated key (enc) and the cipher.
+pub fn seal_auth(
+    algorithm: Algorithm,
+    recipient: &X25519PublicKey,
+    info: &[u8],
+    aad: &[u8],
+    plain: &[u8],
+    sender_private_key: &X25519SecretKey,
+) -> Result<(X25519PublicKey, Vec<u8>)> {
+    let (enc, mut context) = setup_auth_s(algorithm, recipient, info, sender_private_key)?;
+
+    Ok((enc, context.seal(aad, plain)?))
+}
+
+/// OpenAuth. Single-shot authenticated decryption.
+pub fn open_auth(
+    algorithm: Algorithm,
+    enc: &X25519PublicKey,
+    private_key: &X25519SecretKey,
+    info: &[u8],
+    aad: &[u8],
+    cipher: &[u8],
+    sender_public_key: &X25519PublicKey,
+) -> Result<Vec<u8>> {
+    setup_auth_r(algorithm, enc, private_key, info, sender_public_key)?.open(aad, cipher)
+}
@@ -1,24 +1,116 @@
+use std::sync::Arc;
+
+#[cfg(feature = "alloc")]
+pub mod age;
 pub mod asymmetric;
+#[cfg(feature = "alloc")]
+pub mod container;
+#[cfg(feature = "alloc")]
+pub mod envelope;
 pub mod format;
 pub mod hash;
+#[cfg(feature = "alloc")]
+pub mod hpke;
+#[cfg(feature = "alloc")]
+pub mod minisign;
 pub mod rand;
+#[cfg(feature = "alloc")]
+pub mod signify;
 mod size;
+#[cfg(feature = "alloc")]
+pub mod sshsig;
+#[cfg(feature = "alloc")]
+pub mod stream;
 pub mod symmetric;
+pub mod types;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Category of an [`Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A key has the wrong length.
+    InvalidKeyLength,
+    /// A nonce, signature or other input has the wrong length.
+    InvalidLength,
+    /// An AEAD tag, signature or password did not verify.
+    AuthenticationFailed,
+    /// Malformed Base64, Hex, PEM, PHC, key or container bytes.
+    InvalidEncoding,
+    /// The PEM label is not supported.
+    UnsupportedLabel,
+    /// The version, algorithm or kdf is not supported.
+    Unsupported,
+    /// A KDF or password hash parameter is out of range.
+    InvalidParameter,
+    /// The output buffer is too small.
+    BufferTooSmall,
+    /// An I/O error.
+    Io,
+    /// Anything else.
+    Other,
+}
+
 #[derive(Debug, Clone)]
-// pub struct Error {
-//     message: String,
-// }
-pub struct Error(String);
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+    source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+}
 
 impl Error {
-    fn new(message: String) -> Self {
-        Self(message)
+    fn new(kind: ErrorKind, message: String) -> Self {
+        Self {
+            kind,
+            message,
+            source: None,
+        }
+    }
+
+    fn with_source(
+        kind: ErrorKind,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            kind,
+            message: source.to_string(),
+            source: Some(Arc::new(source)),
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
     }
 
     pub fn message(&self) -> &str {
-        &self.0
+        &self.message
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        // Unwrap an xck error that was carried through an io::Read / io::Write.
+        match err
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<Error>())
+        {
+            Some(inner) => inner.clone(),
+            None => Self::with_source(ErrorKind::Io, err),
+        }
     }
 }
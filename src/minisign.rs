@@ -0,0 +1,489 @@
+//! minisign. [https://jedisct1.github.io/minisign/](https://jedisct1.github.io/minisign/)
+//!
+//! Key files and `.minisig` signature files of `minisign -G / -S / -V`. Signatures are prehashed with BLAKE2b-512 (`ED`), or legacy over the whole message (`Ed`).
+//!
+//! The secret-key is encrypted with scrypt (`Sc`) and checked with BLAKE2b-256, or not encrypted (`minisign -W`).
+//!
+//! Keys are the same as signify, a random key number and an Ed25519 key.
+
+extern crate alloc;
+
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+};
+
+use std::io::Read;
+
+use base64ct::{Base64, Encoding};
+
+use zeroize::Zeroizing;
+
+use crate::{
+    asymmetric,
+    hash::{Blake2Params, Blake2bHasher, Hasher},
+    signify,
+    size::{SIZE_32, SIZE_64, SIZE_8},
+    types::{Ed25519PublicKey, Ed25519Signature},
+    Error, ErrorKind, Result,
+};
+
+pub use crate::signify::{gen_keypair, PublicKey, SecretKey};
+
+/// scrypt opslimit of minisign -G, `crypto_pwhash_scryptsalsa208sha256_OPSLIMIT_SENSITIVE`.
+pub const DEFAULT_OPSLIMIT: u64 = 33554432;
+
+/// scrypt memlimit of minisign -G, `crypto_pwhash_scryptsalsa208sha256_MEMLIMIT_SENSITIVE`.
+pub const DEFAULT_MEMLIMIT: u64 = 1073741824;
+
+/// Maximum scrypt memory (128 * r * N) accepted on decoding the secret-key.
+pub const MAX_MEMLIMIT: u64 = DEFAULT_MEMLIMIT;
+
+/// Maximum scrypt opslimit accepted on decoding the secret-key. It bounds the work, N * r * p.
+pub const MAX_OPSLIMIT: u64 = DEFAULT_OPSLIMIT;
+
+/// Untrusted comment of minisign -S.
+pub const DEFAULT_UNTRUSTED_COMMENT: &str = "signature from minisign secret key";
+
+const TRUSTED_COMMENT_PREFIX: &str = "trusted comment: ";
+
+const SIG_ALG: &[u8; 2] = b"Ed";
+
+const SIG_ALG_HASHED: &[u8; 2] = b"ED";
+
+const KDF_ALG: &[u8; 2] = b"Sc";
+
+const KDF_NONE: &[u8; 2] = &[0, 0];
+
+const CHK_ALG: &[u8; 2] = b"B2";
+
+// sig_alg || key_id || public-key
+const PUBLIC_KEY_SIZE: usize = 2 + SIZE_8 + SIZE_32;
+
+// key_id || secret-key || checksum, encrypted as a whole.
+const KEYNUM_SK_SIZE: usize = SIZE_8 + SIZE_64 + SIZE_32;
+
+// sig_alg || kdf_alg || chk_alg || kdf_salt || kdf_opslimit || kdf_memlimit || keynum_sk
+const SECRET_KEY_SIZE: usize = 2 + 2 + 2 + SIZE_32 + SIZE_8 + SIZE_8 + KEYNUM_SK_SIZE;
+
+// sig_alg || key_id || signature
+const SIGNATURE_SIZE: usize = 2 + SIZE_8 + SIZE_64;
+
+/// Encode the public-key file, `untrusted comment: minisign public key <KEY ID>`.
+///
+/// # Example
+/// ```
+/// let (secret_key, public_key) = xck::minisign::gen_keypair();
+///
+/// let encoded = xck::minisign::encode_public_key(&public_key).unwrap();
+///
+/// assert_eq!(xck::minisign::decode_public_key(&encoded).unwrap(), public_key);
+/// ```
+pub fn encode_public_key(public_key: &PublicKey) -> Result<String> {
+    signify::encode_file(
+        &format!("minisign public key {}", key_id_string(&public_key.key_id)),
+        &[
+            &SIG_ALG[..],
+            &public_key.key_id,
+            public_key.public_key.as_bytes(),
+        ]
+        .concat(),
+    )
+}
+
+/// Decode the public-key file, or its base64 line alone (`minisign -P RW...`).
+pub fn decode_public_key(text: &str) -> Result<PublicKey> {
+    let (_, bytes) = signify::decode_lines(&mut text.lines(), true)?;
+
+    if bytes.len() != PUBLIC_KEY_SIZE {
+        Err(Error::new(
+            ErrorKind::InvalidLength,
+            format!("the public-key must be {PUBLIC_KEY_SIZE}-byte."),
+        ))?
+    }
+
+    if &bytes[..2] != SIG_ALG {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "unsupported public-key algorithm.".to_owned(),
+        ))?
+    }
+
+    Ok(PublicKey {
+        key_id: bytes[2..10].try_into().unwrap(),
+        public_key: Ed25519PublicKey::try_from(&bytes[10..])?,
+    })
+}
+
+/// Encode the secret-key file.
+///
+/// With a password the key is encrypted with `DEFAULT_OPSLIMIT` and `DEFAULT_MEMLIMIT` (1 GiB of memory), without it the key is in plain (`minisign -W`).
+pub fn encode_secret_key(secret_key: &SecretKey, password: Option<&[u8]>) -> Result<String> {
+    match password {
+        None => encode_secret_key_with_limits(secret_key, None, 0, 0),
+        Some(password) => encode_secret_key_with_limits(
+            secret_key,
+            Some(password),
+            DEFAULT_OPSLIMIT,
+            DEFAULT_MEMLIMIT,
+        ),
+    }
+}
+
+/// Encode the secret-key file with scrypt opslimit and memlimit of libsodium. They are ignored without a password.
+pub fn encode_secret_key_with_limits(
+    secret_key: &SecretKey,
+    password: Option<&[u8]>,
+    opslimit: u64,
+    memlimit: u64,
+) -> Result<String> {
+    let seckey = Zeroizing::new(signify::nacl_secret_key(&secret_key.private_key));
+
+    let mut keynum_sk = Zeroizing::new(
+        [
+            &secret_key.key_id[..],
+            seckey.as_slice(),
+            &checksum(&secret_key.key_id, seckey.as_slice())?,
+        ]
+        .concat(),
+    );
+
+    let (kdf_alg, salt, opslimit, memlimit) = match password {
+        None => (KDF_NONE, [0u8; SIZE_32], 0, 0),
+        Some(password) => {
+            let salt = crate::rand::gen_32();
+
+            let stream = scrypt_stream(password, &salt, opslimit, memlimit)?;
+
+            for (byte, key) in keynum_sk.iter_mut().zip(stream.iter()) {
+                *byte ^= key;
+            }
+
+            (KDF_ALG, salt, opslimit, memlimit)
+        }
+    };
+
+    let bytes = Zeroizing::new(
+        [
+            &SIG_ALG[..],
+            kdf_alg,
+            CHK_ALG,
+            &salt,
+            &opslimit.to_le_bytes(),
+            &memlimit.to_le_bytes(),
+            keynum_sk.as_slice(),
+        ]
+        .concat(),
+    );
+
+    let comment = match password {
+        None => "minisign secret key",
+        Some(_) => "minisign encrypted secret key",
+    };
+
+    signify::encode_file(comment, &bytes)
+}
+
+/// Decode the secret-key file. password is required if the key is encrypted.
+///
+/// A wrong password is `AuthenticationFailed`.
+pub fn decode_secret_key(text: &str, password: Option<&[u8]>) -> Result<SecretKey> {
+    let (_, bytes) = signify::decode_lines(&mut text.lines(), false)?;
+
+    let bytes = Zeroizing::new(bytes);
+
+    if bytes.len() != SECRET_KEY_SIZE {
+        Err(Error::new(
+            ErrorKind::InvalidLength,
+            format!("the secret-key must be {SECRET_KEY_SIZE}-byte."),
+        ))?
+    }
+
+    if &bytes[..2] != SIG_ALG || &bytes[4..6] != CHK_ALG {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "unsupported secret-key algorithm.".to_owned(),
+        ))?
+    }
+
+    let kdf_alg: &[u8; 2] = bytes[2..4].try_into().unwrap();
+
+    let salt = &bytes[6..38];
+
+    let opslimit = u64::from_le_bytes(bytes[38..46].try_into().unwrap());
+
+    let memlimit = u64::from_le_bytes(bytes[46..54].try_into().unwrap());
+
+    let mut keynum_sk = Zeroizing::new([0u8; KEYNUM_SK_SIZE]);
+
+    keynum_sk.copy_from_slice(&bytes[54..]);
+
+    match (kdf_alg, password) {
+        (KDF_NONE, _) => {}
+        (KDF_ALG, None) => Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "the secret-key is encrypted, a password is required.".to_owned(),
+        ))?,
+        (KDF_ALG, Some(password)) => {
+            let stream = scrypt_stream(password, salt, opslimit, memlimit)?;
+
+            for (byte, key) in keynum_sk.iter_mut().zip(stream.iter()) {
+                *byte ^= key;
+            }
+        }
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            "unsupported minisign kdf algorithm.".to_owned(),
+        ))?,
+    }
+
+    let key_id: [u8; SIZE_8] = keynum_sk[..SIZE_8].try_into().unwrap();
+
+    let seckey = &keynum_sk[SIZE_8..SIZE_8 + SIZE_64];
+
+    if checksum(&key_id, seckey)? != keynum_sk[SIZE_8 + SIZE_64..] {
+        Err(Error::new(
+            ErrorKind::AuthenticationFailed,
+            "the password is wrong or the secret-key is corrupted.".to_owned(),
+        ))?
+    }
+
+    Ok(SecretKey {
+        key_id,
+        private_key: signify::from_nacl_secret_key(seckey)?,
+    })
+}
+
+/// Sign everything from reader, `minisign -S`. The message is prehashed with BLAKE2b-512.
+///
+/// trusted_comment is signed with the signature, minisign writes `timestamp:<unix time>\tfile:<file name>\thashed`.
+///
+/// # Example
+/// ```
+/// let (secret_key, public_key) = xck::minisign::gen_keypair();
+///
+/// let signature = xck::minisign::sign(
+///     &secret_key,
+///     &mut &b"hello"[..],
+///     xck::minisign::DEFAULT_UNTRUSTED_COMMENT,
+///     "file:hello.txt",
+/// )
+/// .unwrap();
+///
+/// let trusted_comment = xck::minisign::verify(&public_key, &signature, &mut &b"hello"[..]).unwrap();
+///
+/// assert_eq!(trusted_comment, "file:hello.txt");
+/// ```
+pub fn sign(
+    secret_key: &SecretKey,
+    reader: &mut impl Read,
+    untrusted_comment: &str,
+    trusted_comment: &str,
+) -> Result<String> {
+    let prehash = blake2b_reader(reader)?;
+
+    sign_with_alg(
+        secret_key,
+        SIG_ALG_HASHED,
+        &prehash,
+        untrusted_comment,
+        trusted_comment,
+    )
+}
+
+/// Sign the message in the legacy mode, `minisign -S -l`. The whole message is signed.
+pub fn sign_legacy(
+    secret_key: &SecretKey,
+    message: &[u8],
+    untrusted_comment: &str,
+    trusted_comment: &str,
+) -> Result<String> {
+    sign_with_alg(
+        secret_key,
+        SIG_ALG,
+        message,
+        untrusted_comment,
+        trusted_comment,
+    )
+}
+
+/// Verify the signature file of everything from reader, `minisign -V`. Prehashed and legacy signatures are both accepted.
+///
+/// Returns the trusted comment, which is verified as well.
+pub fn verify(public_key: &PublicKey, signature: &str, reader: &mut impl Read) -> Result<String> {
+    let invalid = || {
+        Error::new(
+            ErrorKind::InvalidEncoding,
+            "invalid file format.".to_owned(),
+        )
+    };
+
+    let mut lines = signature.lines().map(|line| line.trim_end_matches('\r'));
+
+    let (_, bytes) = signify::decode_lines(&mut lines, false)?;
+
+    let trusted_comment = lines
+        .next()
+        .and_then(|line| line.strip_prefix(TRUSTED_COMMENT_PREFIX))
+        .ok_or_else(invalid)?;
+
+    let global_signature = lines
+        .next()
+        .and_then(|line| Base64::decode_vec(line.trim()).ok())
+        .ok_or_else(invalid)?;
+
+    if bytes.len() != SIGNATURE_SIZE {
+        Err(Error::new(
+            ErrorKind::InvalidLength,
+            format!("the signature must be {SIGNATURE_SIZE}-byte."),
+        ))?
+    }
+
+    signify::check_key_id(&public_key.key_id, &bytes[2..10])?;
+
+    let message = match &bytes[..2] {
+        alg if alg == SIG_ALG_HASHED => blake2b_reader(reader)?.to_vec(),
+        alg if alg == SIG_ALG => {
+            let mut message = alloc::vec::Vec::new();
+
+            reader.read_to_end(&mut message)?;
+
+            message
+        }
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            "unsupported signature algorithm.".to_owned(),
+        ))?,
+    };
+
+    let signature = Ed25519Signature::try_from(&bytes[10..])?;
+
+    asymmetric::ed25519_verify(&public_key.public_key, &message, &signature)?;
+
+    asymmetric::ed25519_verify(
+        &public_key.public_key,
+        &[signature.as_bytes(), trusted_comment.as_bytes()].concat(),
+        &Ed25519Signature::try_from(global_signature.as_slice())?,
+    )?;
+
+    Ok(trusted_comment.to_string())
+}
+
+fn sign_with_alg(
+    secret_key: &SecretKey,
+    sig_alg: &[u8; 2],
+    message: &[u8],
+    untrusted_comment: &str,
+    trusted_comment: &str,
+) -> Result<String> {
+    if trusted_comment.contains(['\r', '\n']) {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "the trusted comment must be one line.".to_owned(),
+        ))?
+    }
+
+    let signature = asymmetric::ed25519_sign(&secret_key.private_key, message)?;
+
+    // The global signature covers the signature and the trusted comment.
+    let global_signature = asymmetric::ed25519_sign(
+        &secret_key.private_key,
+        &[signature.as_bytes(), trusted_comment.as_bytes()].concat(),
+    )?;
+
+    let file = signify::encode_file(
+        untrusted_comment,
+        &[&sig_alg[..], &secret_key.key_id, signature.as_bytes()].concat(),
+    )?;
+
+    Ok(format!(
+        "{file}{TRUSTED_COMMENT_PREFIX}{trusted_comment}\n{}\n",
+        Base64::encode_string(global_signature.as_bytes())
+    ))
+}
+
+// Hex of the little-endian key id, the same as minisign prints.
+fn key_id_string(key_id: &[u8; SIZE_8]) -> String {
+    format!("{:016X}", u64::from_le_bytes(*key_id))
+}
+
+// BLAKE2b-256(sig_alg || key_id || secret-key)
+fn checksum(key_id: &[u8; SIZE_8], seckey: &[u8]) -> Result<[u8; SIZE_32]> {
+    let mut hasher = Blake2bHasher::with_params(&Blake2Params::default(), SIZE_32)?;
+
+    hasher.update(SIG_ALG);
+
+    hasher.update(key_id);
+
+    hasher.update(seckey);
+
+    let mut checksum = [0u8; SIZE_32];
+
+    hasher.finalize_into(&mut checksum)?;
+
+    Ok(checksum)
+}
+
+fn blake2b_reader(reader: &mut impl Read) -> Result<[u8; SIZE_64]> {
+    let mut hasher = Blake2bHasher::new();
+
+    std::io::copy(reader, &mut hasher)?;
+
+    let mut digest = [0u8; SIZE_64];
+
+    hasher.finalize_into(&mut digest)?;
+
+    Ok(digest)
+}
+
+// crypto_pwhash_scryptsalsa208sha256 of libsodium, the scrypt parameters are picked from opslimit and memlimit.
+fn scrypt_stream(
+    password: &[u8],
+    salt: &[u8],
+    opslimit: u64,
+    memlimit: u64,
+) -> Result<Zeroizing<[u8; KEYNUM_SK_SIZE]>> {
+    if opslimit > MAX_OPSLIMIT {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            format!("the scrypt opslimit must be at most {MAX_OPSLIMIT}."),
+        ))?
+    }
+
+    let opslimit = opslimit.max(32768);
+
+    let r = 8u64;
+
+    let n_log2 = |max_n: u64| (1..63).find(|n| 1u64 << n > max_n / 2).unwrap_or(63);
+
+    let (log_n, p) = if opslimit < memlimit / 32 {
+        (n_log2(opslimit / (r * 4)), 1)
+    } else {
+        let log_n = n_log2(memlimit / (r * 128));
+
+        let max_rp = ((opslimit / 4) >> log_n).min(0x3fffffff);
+
+        (log_n, max_rp / r)
+    };
+
+    if (128 * r) << log_n > MAX_MEMLIMIT {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            format!("the scrypt memory must be at most {MAX_MEMLIMIT}-byte."),
+        ))?
+    }
+
+    let params = scrypt::Params::new(log_n as u8, r as u32, p as u32, SIZE_32).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidParameter,
+            format!("invalid scrypt limits: opslimit {opslimit}, memlimit {memlimit}."),
+        )
+    })?;
+
+    let mut stream = Zeroizing::new([0u8; KEYNUM_SK_SIZE]);
+
+    scrypt::scrypt(password, salt, &params, stream.as_mut())
+        .map_err(|_| Error::new(ErrorKind::Other, "scrypt failed.".to_owned()))?;
+
+    Ok(stream)
+}
@@ -127,8 +127,8 @@ pub fn generate_ascii() -> [u8; SIZE_32] {
 pub fn gen_64_ascii() -> [u8; SIZE_64] {
     let mut buf = [0u8; SIZE_64];
 
-    for i in 0..SIZE_64 {
-        buf[i] = gen_range_ascii();
+    for byte in buf.iter_mut() {
+        *byte = gen_range_ascii();
     }
 
     buf
@@ -140,8 +140,8 @@ pub fn gen_64_ascii() -> [u8; SIZE_64] {
 pub fn gen_32_ascii() -> [u8; SIZE_32] {
     let mut buf = [0u8; SIZE_32];
 
-    for i in 0..SIZE_32 {
-        buf[i] = gen_range_ascii();
+    for byte in buf.iter_mut() {
+        *byte = gen_range_ascii();
     }
 
     buf
@@ -153,8 +153,8 @@ pub fn gen_32_ascii() -> [u8; SIZE_32] {
 pub fn gen_24_ascii() -> [u8; SIZE_24] {
     let mut buf = [0u8; SIZE_24];
 
-    for i in 0..SIZE_24 {
-        buf[i] = gen_range_ascii();
+    for byte in buf.iter_mut() {
+        *byte = gen_range_ascii();
     }
 
     buf
@@ -166,8 +166,8 @@ pub fn gen_24_ascii() -> [u8; SIZE_24] {
 pub fn gen_16_ascii() -> [u8; SIZE_16] {
     let mut buf = [0u8; SIZE_16];
 
-    for i in 0..SIZE_16 {
-        buf[i] = gen_range_ascii();
+    for byte in buf.iter_mut() {
+        *byte = gen_range_ascii();
     }
 
     buf
@@ -179,8 +179,8 @@ pub fn gen_16_ascii() -> [u8; SIZE_16] {
 pub fn gen_12_ascii() -> [u8; SIZE_12] {
     let mut buf = [0u8; SIZE_12];
 
-    for i in 0..SIZE_12 {
-        buf[i] = gen_range_ascii();
+    for byte in buf.iter_mut() {
+        *byte = gen_range_ascii();
     }
 
     buf
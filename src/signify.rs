@@ -0,0 +1,359 @@
+//! OpenBSD signify. [signify(1)](https://man.openbsd.org/signify)
+//!
+//! Key files and `.sig` signature files of `signify -G / -S / -V`. The secret-key is encrypted with bcrypt-pbkdf, or not with `rounds = 0` (`signify -n`).
+//!
+//! Every file is an `untrusted comment: ` line and a base64 line, the same as minisign which is derived from it.
+
+extern crate alloc;
+
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use base64ct::{Base64, Encoding};
+
+use sha2::Digest;
+
+use zeroize::Zeroizing;
+
+use crate::{
+    asymmetric,
+    size::{SIZE_16, SIZE_32, SIZE_64, SIZE_8},
+    types::{Ed25519PublicKey, Ed25519SecretKey, Ed25519Signature},
+    Error, ErrorKind, Result,
+};
+
+/// bcrypt-pbkdf rounds of signify -G.
+pub const DEFAULT_ROUNDS: u32 = 42;
+
+/// Maximum bcrypt-pbkdf rounds accepted on decoding the secret-key.
+pub const MAX_ROUNDS: u32 = 1024;
+
+const UNTRUSTED_COMMENT_PREFIX: &str = "untrusted comment: ";
+
+const PK_ALG: &[u8; 2] = b"Ed";
+
+const KDF_ALG: &[u8; 2] = b"BK";
+
+// pkalg || keynum || pubkey
+const PUBLIC_KEY_SIZE: usize = 2 + SIZE_8 + SIZE_32;
+
+// pkalg || kdfalg || kdfrounds || salt || checksum || keynum || seckey
+const SECRET_KEY_SIZE: usize = 2 + 2 + 4 + SIZE_16 + SIZE_8 + SIZE_8 + SIZE_64;
+
+// pkalg || keynum || sig
+const SIGNATURE_SIZE: usize = 2 + SIZE_8 + SIZE_64;
+
+/// signify public-key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    /// Random key number. A signature names the key that made it.
+    pub key_id: [u8; SIZE_8],
+
+    pub public_key: Ed25519PublicKey,
+}
+
+/// signify secret-key.
+#[derive(Debug, Clone)]
+pub struct SecretKey {
+    pub key_id: [u8; SIZE_8],
+
+    pub private_key: Ed25519SecretKey,
+}
+
+impl SecretKey {
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            key_id: self.key_id,
+            public_key: asymmetric::ed25519_gen_public_key(&self.private_key),
+        }
+    }
+}
+
+/// Generate a keypair with a random key number, `signify -G`.
+///
+/// # Example
+/// ```
+/// let (secret_key, public_key) = xck::signify::gen_keypair();
+///
+/// let signature = xck::signify::sign(&secret_key, b"hello", "verify with key.pub").unwrap();
+///
+/// assert!(xck::signify::verify(&public_key, &signature, b"hello").is_ok());
+/// ```
+pub fn gen_keypair() -> (SecretKey, PublicKey) {
+    let secret_key = SecretKey {
+        key_id: gen_key_id(),
+        private_key: asymmetric::ed25519_gen_private_key(),
+    };
+
+    let public_key = secret_key.public_key();
+
+    (secret_key, public_key)
+}
+
+/// Encode the public-key file. comment is followed by ` public key`, e.g. `signify`.
+pub fn encode_public_key(public_key: &PublicKey, comment: &str) -> Result<String> {
+    encode_file(
+        &format!("{comment} public key"),
+        &[
+            &PK_ALG[..],
+            &public_key.key_id,
+            public_key.public_key.as_bytes(),
+        ]
+        .concat(),
+    )
+}
+
+/// Decode the public-key file, or its base64 line alone.
+pub fn decode_public_key(text: &str) -> Result<PublicKey> {
+    let (_, bytes) = decode_lines(&mut text.lines(), true)?;
+
+    let bytes = check_alg(&bytes, PUBLIC_KEY_SIZE, "public-key")?;
+
+    Ok(PublicKey {
+        key_id: bytes[2..10].try_into().unwrap(),
+        public_key: Ed25519PublicKey::try_from(&bytes[10..])?,
+    })
+}
+
+/// Encode the secret-key file. comment is followed by ` secret key`.
+///
+/// With a passphrase the key is encrypted with `DEFAULT_ROUNDS` of bcrypt-pbkdf, without it the key is in plain (`signify -n`).
+pub fn encode_secret_key(
+    secret_key: &SecretKey,
+    comment: &str,
+    passphrase: Option<&[u8]>,
+) -> Result<String> {
+    let salt = crate::rand::gen_16();
+
+    let rounds = passphrase.map_or(0, |_| DEFAULT_ROUNDS);
+
+    let mut seckey = Zeroizing::new(nacl_secret_key(&secret_key.private_key));
+
+    let checksum = sha2::Sha512::digest(seckey.as_slice());
+
+    for (byte, key) in seckey
+        .iter_mut()
+        .zip(kdf(passphrase, &salt, rounds)?.iter())
+    {
+        *byte ^= key;
+    }
+
+    let bytes = Zeroizing::new(
+        [
+            &PK_ALG[..],
+            KDF_ALG,
+            &rounds.to_be_bytes(),
+            &salt,
+            &checksum[..SIZE_8],
+            &secret_key.key_id,
+            seckey.as_slice(),
+        ]
+        .concat(),
+    );
+
+    encode_file(&format!("{comment} secret key"), &bytes)
+}
+
+/// Decode the secret-key file. passphrase is required if the key is encrypted.
+///
+/// A wrong passphrase is `AuthenticationFailed`.
+pub fn decode_secret_key(text: &str, passphrase: Option<&[u8]>) -> Result<SecretKey> {
+    let (_, bytes) = decode_lines(&mut text.lines(), false)?;
+
+    let bytes = Zeroizing::new(bytes);
+
+    let bytes = check_alg(&bytes, SECRET_KEY_SIZE, "secret-key")?;
+
+    if &bytes[2..4] != KDF_ALG {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "unsupported signify kdf algorithm.".to_owned(),
+        ))?
+    }
+
+    let rounds = u32::from_be_bytes(bytes[4..8].try_into().unwrap());
+
+    if rounds > MAX_ROUNDS {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            format!("the bcrypt-pbkdf rounds must be at most {MAX_ROUNDS}."),
+        ))?
+    }
+
+    if rounds > 0 && passphrase.is_none() {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "the secret-key is encrypted, a passphrase is required.".to_owned(),
+        ))?
+    }
+
+    let (salt, checksum, key_id) = (&bytes[8..24], &bytes[24..32], &bytes[32..40]);
+
+    let mut seckey = Zeroizing::new([0u8; SIZE_64]);
+
+    seckey.copy_from_slice(&bytes[40..]);
+
+    for (byte, key) in seckey.iter_mut().zip(kdf(passphrase, salt, rounds)?.iter()) {
+        *byte ^= key;
+    }
+
+    if &sha2::Sha512::digest(seckey.as_slice())[..SIZE_8] != checksum {
+        Err(Error::new(
+            ErrorKind::AuthenticationFailed,
+            "the passphrase is wrong or the secret-key is corrupted.".to_owned(),
+        ))?
+    }
+
+    Ok(SecretKey {
+        key_id: key_id.try_into().unwrap(),
+        private_key: from_nacl_secret_key(seckey.as_slice())?,
+    })
+}
+
+/// Sign the message, `signify -S`. Returns the signature file, comment is its untrusted comment.
+///
+/// signify names it `verify with <public-key file>`.
+pub fn sign(secret_key: &SecretKey, message: &[u8], comment: &str) -> Result<String> {
+    let signature = asymmetric::ed25519_sign(&secret_key.private_key, message)?;
+
+    encode_file(
+        comment,
+        &[&PK_ALG[..], &secret_key.key_id, signature.as_bytes()].concat(),
+    )
+}
+
+/// Verify the signature file of the message, `signify -V`.
+///
+/// The key number of the signature must be the same as the public-key.
+pub fn verify(public_key: &PublicKey, signature: &str, message: &[u8]) -> Result<()> {
+    let (_, bytes) = decode_lines(&mut signature.lines(), false)?;
+
+    let bytes = check_alg(&bytes, SIGNATURE_SIZE, "signature")?;
+
+    check_key_id(&public_key.key_id, &bytes[2..10])?;
+
+    asymmetric::ed25519_verify(
+        &public_key.public_key,
+        message,
+        &Ed25519Signature::try_from(&bytes[10..])?,
+    )
+}
+
+pub(crate) fn gen_key_id() -> [u8; SIZE_8] {
+    crate::rand::gen_16()[..SIZE_8].try_into().unwrap()
+}
+
+pub(crate) fn check_key_id(key_id: &[u8; SIZE_8], signature_key_id: &[u8]) -> Result<()> {
+    if key_id != signature_key_id {
+        Err(Error::new(
+            ErrorKind::AuthenticationFailed,
+            "the signature was made by another key.".to_owned(),
+        ))?
+    }
+
+    Ok(())
+}
+
+// `untrusted comment: <comment>` line and base64 line.
+pub(crate) fn encode_file(comment: &str, bytes: &[u8]) -> Result<String> {
+    if comment.contains(['\r', '\n']) {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "the comment must be one line.".to_owned(),
+        ))?
+    }
+
+    Ok(format!(
+        "{UNTRUSTED_COMMENT_PREFIX}{comment}\n{}\n",
+        Base64::encode_string(bytes)
+    ))
+}
+
+// The untrusted comment and the bytes of the base64 line. bare allows the base64 line alone.
+pub(crate) fn decode_lines<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    bare: bool,
+) -> Result<(String, Vec<u8>)> {
+    let invalid = || {
+        Error::new(
+            ErrorKind::InvalidEncoding,
+            "invalid file format.".to_owned(),
+        )
+    };
+
+    let mut lines = lines.map(|line| line.trim_end_matches('\r'));
+
+    let (comment, line) = match lines.next().ok_or_else(invalid)? {
+        line if line.starts_with(UNTRUSTED_COMMENT_PREFIX) => (
+            line[UNTRUSTED_COMMENT_PREFIX.len()..].to_string(),
+            lines.next().ok_or_else(invalid)?,
+        ),
+        line if bare => (String::new(), line.trim()),
+        _ => Err(invalid())?,
+    };
+
+    let bytes = Base64::decode_vec(line).map_err(|_| invalid())?;
+
+    Ok((comment, bytes))
+}
+
+fn check_alg<'a>(bytes: &'a [u8], size: usize, name: &str) -> Result<&'a [u8]> {
+    if bytes.len() != size {
+        Err(Error::new(
+            ErrorKind::InvalidLength,
+            format!("the {name} must be {size}-byte."),
+        ))?
+    }
+
+    if &bytes[..2] != PK_ALG {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("unsupported {name} algorithm."),
+        ))?
+    }
+
+    Ok(bytes)
+}
+
+// Ed25519 secret-key of NaCl, seed || public-key.
+pub(crate) fn nacl_secret_key(private_key: &Ed25519SecretKey) -> [u8; SIZE_64] {
+    let mut seckey = [0u8; SIZE_64];
+
+    seckey[..SIZE_32].copy_from_slice(private_key.as_bytes());
+
+    seckey[SIZE_32..].copy_from_slice(asymmetric::ed25519_gen_public_key(private_key).as_bytes());
+
+    seckey
+}
+
+pub(crate) fn from_nacl_secret_key(seckey: &[u8]) -> Result<Ed25519SecretKey> {
+    let private_key = Ed25519SecretKey::try_from(&seckey[..SIZE_32])?;
+
+    if asymmetric::ed25519_gen_public_key(&private_key).as_bytes()[..] != seckey[SIZE_32..] {
+        Err(Error::new(
+            ErrorKind::InvalidEncoding,
+            "the public-key in the secret-key does not match.".to_owned(),
+        ))?
+    }
+
+    Ok(private_key)
+}
+
+// No passphrase (rounds = 0) is a zero key.
+fn kdf(passphrase: Option<&[u8]>, salt: &[u8], rounds: u32) -> Result<Zeroizing<[u8; SIZE_64]>> {
+    let mut key = Zeroizing::new([0u8; SIZE_64]);
+
+    if let (Some(passphrase), 1..) = (passphrase, rounds) {
+        bcrypt_pbkdf::bcrypt_pbkdf(passphrase, salt, rounds, key.as_mut()).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidParameter,
+                "the passphrase must not be empty.".to_owned(),
+            )
+        })?;
+    }
+
+    Ok(key)
+}
@@ -15,6 +15,9 @@ pub const SIZE_16: usize = 16;
 /// 12-byte
 pub const SIZE_12: usize = 12;
 
+/// 8-byte
+pub const SIZE_8: usize = 8;
+
 /// 51-byte
 pub const SIZE_51: usize = 51;
 
@@ -0,0 +1,568 @@
+//! SSH signature (SSHSIG) of OpenSSH. [PROTOCOL.sshsig](https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.sshsig)
+//!
+//! Ed25519 keys only. Signatures are the armored `-----BEGIN SSH SIGNATURE-----` of `ssh-keygen -Y sign`, and the message is hashed in a streaming fashion.
+//!
+//! Signers are checked against an `allowed_signers` file (see ssh-keygen(1) ALLOWED SIGNERS), the same as git with `gpg.format = ssh`.
+
+extern crate alloc;
+
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use std::io::Read;
+
+use base64ct::{Base64Unpadded, Encoding};
+
+use crate::{
+    asymmetric,
+    hash::{self, HashAlgorithm, Sha256Hasher},
+    types::{Ed25519PublicKey, Ed25519SecretKey, Ed25519Signature},
+    Error, ErrorKind, Result,
+};
+
+const MAGIC_PREAMBLE: &[u8] = b"SSHSIG";
+
+const ED25519_KEY_TYPE: &str = "ssh-ed25519";
+
+/// Sign everything from reader with the namespace, `ssh-keygen -Y sign -n namespace`.
+///
+/// The message is hashed with SHA512. Returns the armored signature.
+///
+/// # Example
+/// ```
+/// let (private_key, public_key) = xck::asymmetric::ed25519_gen_keypair();
+///
+/// let signature = xck::sshsig::sign(&private_key, "file", &mut &b"hello"[..]).unwrap();
+///
+/// let signer = xck::sshsig::verify(&signature, "file", &mut &b"hello"[..]).unwrap();
+///
+/// assert_eq!(signer, public_key);
+/// ```
+pub fn sign(
+    private_key: &Ed25519SecretKey,
+    namespace: &str,
+    reader: &mut impl Read,
+) -> Result<String> {
+    let hash_alg = ssh_key::HashAlg::Sha512;
+
+    let signed_data = signed_data(namespace, &[], hash_alg, reader)?;
+
+    let signature = asymmetric::ed25519_sign(private_key, &signed_data)?;
+
+    let public_key = asymmetric::ed25519_gen_public_key(private_key);
+
+    let signature = ssh_key::Signature::new(ssh_key::Algorithm::Ed25519, signature.to_bytes())
+        .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?;
+
+    ssh_key::SshSig::new(
+        ssh_key::public::Ed25519PublicKey(public_key.to_bytes()).into(),
+        namespace,
+        hash_alg,
+        signature,
+    )
+    .and_then(|sshsig| sshsig.to_pem(ssh_key::LineEnding::LF))
+    .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))
+}
+
+/// Verify the armored signature of everything from reader, `ssh-keygen -Y check-novalidate`.
+///
+/// Returns the public-key of the signer. It is not trusted yet, look it up in `allowed_signers` with `AllowedSigners::verify`.
+pub fn verify(
+    signature: &str,
+    namespace: &str,
+    reader: &mut impl Read,
+) -> Result<Ed25519PublicKey> {
+    let sshsig = ssh_key::SshSig::from_pem(signature.trim())
+        .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?;
+
+    if sshsig.namespace() != namespace {
+        Err(Error::new(
+            ErrorKind::AuthenticationFailed,
+            format!(
+                "the signature namespace {} does not match {namespace}.",
+                sshsig.namespace()
+            ),
+        ))?
+    }
+
+    let public_key = signer(&sshsig)?;
+
+    let signed_data = signed_data(namespace, sshsig.reserved(), sshsig.hash_alg(), reader)?;
+
+    let signature = Ed25519Signature::try_from(sshsig.signature_bytes())?;
+
+    asymmetric::ed25519_verify(&public_key, &signed_data, &signature)?;
+
+    Ok(public_key)
+}
+
+/// The public-key of the signer and the namespace of the armored signature. The signature is not verified.
+pub fn inspect(signature: &str) -> Result<(Ed25519PublicKey, String)> {
+    let sshsig = ssh_key::SshSig::from_pem(signature.trim())
+        .map_err(|err| Error::with_source(ErrorKind::InvalidEncoding, err))?;
+
+    Ok((signer(&sshsig)?, sshsig.namespace().to_owned()))
+}
+
+/// SHA256 fingerprint of the public-key, `SHA256:...` the same as ssh-keygen -l.
+pub fn fingerprint(public_key: &Ed25519PublicKey) -> String {
+    let mut hasher = Sha256Hasher::new();
+
+    hasher.update(&wire_string(ED25519_KEY_TYPE.as_bytes()));
+
+    hasher.update(&wire_string(public_key.as_bytes()));
+
+    format!(
+        "SHA256:{}",
+        Base64Unpadded::encode_string(&hasher.finalize())
+    )
+}
+
+/// Parse a timestamp of allowed_signers and `-Overify-time`, `YYYYMMDD[HHMM[SS]][Z|UTC]`.
+///
+/// Returns seconds since the UNIX epoch. With `Z` or `UTC` the time is UTC, otherwise it is the local time, as `parse_absolute_time` of OpenSSH. (UTC on non-unix targets)
+pub fn parse_time(time: &str) -> Result<u64> {
+    let invalid = || {
+        Error::new(
+            ErrorKind::InvalidEncoding,
+            format!("invalid timestamp: {time}."),
+        )
+    };
+
+    let (digits, utc) = match time
+        .strip_suffix(['Z', 'z'])
+        .or_else(|| time.strip_suffix("UTC"))
+    {
+        Some(digits) => (digits, true),
+        None => (time, false),
+    };
+
+    if !matches!(digits.len(), 8 | 12 | 14) || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        Err(invalid())?
+    }
+
+    let field = |range: core::ops::Range<usize>| -> u64 {
+        digits
+            .get(range)
+            .map_or(0, |field| field.parse().unwrap_or(0))
+    };
+
+    let (year, month, day) = (field(0..4), field(4..6), field(6..8));
+
+    let (hour, minute, second) = (field(8..10), field(10..12), field(12..14));
+
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+
+    let days_in_month = match month {
+        2 if leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+        || year < 1970
+    {
+        Err(invalid())?
+    }
+
+    if !utc {
+        return local_time(year, month, day, hour, minute, second).ok_or_else(invalid);
+    }
+
+    // Days from the civil date. [http://howardhinnant.github.io/date_algorithms.html](http://howardhinnant.github.io/date_algorithms.html)
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+
+    let era = year / 400;
+
+    let year_of_era = year - era * 400;
+
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    let days = era * 146097 + day_of_era - 719468;
+
+    Ok(days * 86400 + hour * 3600 + minute * 60 + second)
+}
+
+// mktime(3) with a zeroed tm (tm_isdst = 0), the same as OpenSSH. None before the epoch.
+#[cfg(unix)]
+fn local_time(year: u64, month: u64, day: u64, hour: u64, minute: u64, second: u64) -> Option<u64> {
+    // SAFETY: tm is plain old data, all zero is a valid value.
+    let mut tm: libc::tm = unsafe { core::mem::zeroed() };
+
+    tm.tm_year = i32::try_from(year).ok()? - 1900;
+    tm.tm_mon = month as i32 - 1;
+    tm.tm_mday = day as i32;
+    tm.tm_hour = hour as i32;
+    tm.tm_min = minute as i32;
+    tm.tm_sec = second as i32;
+
+    // SAFETY: tm is a valid, exclusively borrowed tm.
+    let time = unsafe { libc::mktime(&mut tm) };
+
+    u64::try_from(time).ok()
+}
+
+#[cfg(not(unix))]
+fn local_time(year: u64, month: u64, day: u64, hour: u64, minute: u64, second: u64) -> Option<u64> {
+    parse_time(&format!(
+        "{year:04}{month:02}{day:02}{hour:02}{minute:02}{second:02}Z"
+    ))
+    .ok()
+}
+
+/// One line of an allowed_signers file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowedSigner {
+    /// Comma-separated principal patterns, e.g. `alice@example.com,*@example.org`.
+    pub principals: String,
+
+    /// Comma-separated namespace patterns of `namespaces="..."`. `None` allows any namespace.
+    pub namespaces: Option<String>,
+
+    /// `valid-after` in seconds since the UNIX epoch.
+    pub valid_after: Option<u64>,
+
+    /// `valid-before` in seconds since the UNIX epoch.
+    pub valid_before: Option<u64>,
+
+    pub public_key: Ed25519PublicKey,
+}
+
+impl AllowedSigner {
+    /// Whether the public-key may sign in the namespace at time (seconds since the UNIX epoch).
+    pub fn allows(&self, public_key: &Ed25519PublicKey, namespace: &str, time: u64) -> bool {
+        &self.public_key == public_key
+            && !matches!(&self.namespaces, Some(namespaces) if !match_pattern_list(namespaces, namespace))
+            && !matches!(self.valid_after, Some(after) if time < after)
+            && !matches!(self.valid_before, Some(before) if time > before)
+    }
+
+    /// Whether principal matches the principal patterns.
+    pub fn matches_principal(&self, principal: &str) -> bool {
+        match_pattern_list(&self.principals, principal)
+    }
+}
+
+/// allowed_signers file.
+///
+/// Only `ssh-ed25519` keys are read. Lines of other key types and `cert-authority` lines are skipped.
+///
+/// # Example
+/// ```
+/// let (private_key, public_key) = xck::asymmetric::ed25519_gen_keypair();
+///
+/// let line = xck::asymmetric::ssh_ed25519_public_key_encode(&public_key, "").unwrap();
+///
+/// let allowed_signers = xck::sshsig::AllowedSigners::parse(&format!("alice@example.com namespaces=\"git\" {line}\n")).unwrap();
+///
+/// let signature = xck::sshsig::sign(&private_key, "git", &mut &b"hello"[..]).unwrap();
+///
+/// assert!(allowed_signers.verify(&signature, "git", "alice@example.com", 0, &mut &b"hello"[..]).is_ok());
+///
+/// assert!(allowed_signers.verify(&signature, "git", "bob@example.com", 0, &mut &b"hello"[..]).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowedSigners(pub Vec<AllowedSigner>);
+
+impl AllowedSigners {
+    /// Parse an allowed_signers file. Empty lines and `#` comments are skipped.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut signers = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = |message: &str| {
+                Error::new(
+                    ErrorKind::InvalidEncoding,
+                    format!("allowed_signers line {}: {message}.", number + 1),
+                )
+            };
+
+            let fields = split_fields(line).ok_or(invalid("unterminated quote"))?;
+
+            let mut fields = fields.into_iter();
+
+            let principals = fields.next().ok_or(invalid("missing principals"))?;
+
+            let mut field = fields.next().ok_or(invalid("missing key"))?;
+
+            let mut signer = AllowedSigner {
+                principals: unquote(&principals),
+                namespaces: None,
+                valid_after: None,
+                valid_before: None,
+                public_key: Ed25519PublicKey::from([0u8; 32]),
+            };
+
+            let mut cert_authority = false;
+
+            // Options are present if the field is not a key type.
+            if !is_key_type(&field) {
+                for option in split_options(&field) {
+                    let (name, value) = match option.split_once('=') {
+                        None => (option.as_str(), None),
+                        Some((name, value)) => (name, Some(unquote(value))),
+                    };
+
+                    match (name.to_ascii_lowercase().as_str(), value) {
+                        ("cert-authority", None) => cert_authority = true,
+                        ("namespaces", Some(value)) => signer.namespaces = Some(value),
+                        ("valid-after", Some(value)) => {
+                            signer.valid_after = Some(parse_time(&value)?)
+                        }
+                        ("valid-before", Some(value)) => {
+                            signer.valid_before = Some(parse_time(&value)?)
+                        }
+                        _ => Err(invalid("unknown option"))?,
+                    }
+                }
+
+                field = fields.next().ok_or(invalid("missing key"))?;
+            }
+
+            let key = fields.next().ok_or(invalid("missing key"))?;
+
+            if cert_authority || field != ED25519_KEY_TYPE {
+                continue;
+            }
+
+            (signer.public_key, _) =
+                asymmetric::ssh_ed25519_public_key_decode(&format!("{field} {key}"))?;
+
+            signers.push(signer);
+        }
+
+        Ok(Self(signers))
+    }
+
+    /// Principals allowed to sign with the public-key, `ssh-keygen -Y find-principals`.
+    pub fn find_principals(
+        &self,
+        public_key: &Ed25519PublicKey,
+        namespace: &str,
+        time: u64,
+    ) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|signer| signer.allows(public_key, namespace, time))
+            .map(|signer| signer.principals.as_str())
+            .collect()
+    }
+
+    /// Verify the armored signature and that principal may sign with its key, `ssh-keygen -Y verify`.
+    ///
+    /// time is seconds since the UNIX epoch, checked against `valid-after` and `valid-before`. Returns the public-key of the signer.
+    pub fn verify(
+        &self,
+        signature: &str,
+        namespace: &str,
+        principal: &str,
+        time: u64,
+        reader: &mut impl Read,
+    ) -> Result<Ed25519PublicKey> {
+        let public_key = verify(signature, namespace, reader)?;
+
+        if !self.0.iter().any(|signer| {
+            signer.allows(&public_key, namespace, time) && signer.matches_principal(principal)
+        }) {
+            Err(Error::new(
+                ErrorKind::AuthenticationFailed,
+                format!("{principal} is not an allowed signer of the key."),
+            ))?
+        }
+
+        Ok(public_key)
+    }
+}
+
+fn signer(sshsig: &ssh_key::SshSig) -> Result<Ed25519PublicKey> {
+    match sshsig.public_key().ed25519() {
+        Some(public_key) if sshsig.algorithm() == ssh_key::Algorithm::Ed25519 => {
+            Ok(Ed25519PublicKey::from(public_key.0))
+        }
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!(
+                "unsupported ssh signature algorithm: {}.",
+                sshsig.algorithm()
+            ),
+        )),
+    }
+}
+
+// "SSHSIG" || string(namespace) || string(reserved) || string(hash_algorithm) || string(H(message))
+fn signed_data(
+    namespace: &str,
+    reserved: &[u8],
+    hash_alg: ssh_key::HashAlg,
+    reader: &mut impl Read,
+) -> Result<Vec<u8>> {
+    if namespace.is_empty() {
+        Err(Error::new(
+            ErrorKind::InvalidParameter,
+            "the namespace must not be empty.".to_owned(),
+        ))?
+    }
+
+    let algorithm = match hash_alg {
+        ssh_key::HashAlg::Sha256 => HashAlgorithm::Sha256,
+        ssh_key::HashAlg::Sha512 => HashAlgorithm::Sha512,
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("unsupported ssh signature hash algorithm: {hash_alg}."),
+        ))?,
+    };
+
+    let digest = hash::hash_reader(algorithm, reader)?;
+
+    Ok([
+        MAGIC_PREAMBLE,
+        &wire_string(namespace.as_bytes()),
+        &wire_string(reserved),
+        &wire_string(hash_alg.as_str().as_bytes()),
+        &wire_string(&digest),
+    ]
+    .concat())
+}
+
+// string of RFC 4251.
+fn wire_string(bytes: &[u8]) -> Vec<u8> {
+    [&(bytes.len() as u32).to_be_bytes()[..], bytes].concat()
+}
+
+fn is_key_type(field: &str) -> bool {
+    field.starts_with("ssh-") || field.starts_with("ecdsa-") || field.starts_with("sk-")
+}
+
+// Split by whitespace outside of double quotes.
+fn split_fields(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+
+    let mut field = String::new();
+
+    let mut quoted = false;
+
+    for char in line.chars() {
+        match char {
+            '"' => {
+                quoted = !quoted;
+                field.push(char);
+            }
+            char if char.is_whitespace() && !quoted => {
+                if !field.is_empty() {
+                    fields.push(core::mem::take(&mut field));
+                }
+            }
+            char => field.push(char),
+        }
+    }
+
+    if quoted {
+        return None;
+    }
+
+    if !field.is_empty() {
+        fields.push(field);
+    }
+
+    Some(fields)
+}
+
+// Split by commas outside of double quotes.
+fn split_options(options: &str) -> Vec<String> {
+    let mut split = Vec::new();
+
+    let mut option = String::new();
+
+    let mut quoted = false;
+
+    for char in options.chars() {
+        match char {
+            '"' => {
+                quoted = !quoted;
+                option.push(char);
+            }
+            ',' if !quoted => split.push(core::mem::take(&mut option)),
+            char => option.push(char),
+        }
+    }
+
+    split.push(option);
+
+    split
+}
+
+fn unquote(value: &str) -> String {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
+
+// match_pattern_list of OpenSSH. A negated pattern (!) that matches rejects.
+fn match_pattern_list(patterns: &str, string: &str) -> bool {
+    let mut matched = false;
+
+    for pattern in patterns.split(',').map(str::trim) {
+        match pattern.strip_prefix('!') {
+            Some(pattern) if match_pattern(pattern.as_bytes(), string.as_bytes()) => return false,
+            Some(_) => {}
+            None if match_pattern(pattern.as_bytes(), string.as_bytes()) => matched = true,
+            None => {}
+        }
+    }
+
+    matched
+}
+
+// `*` is any sequence and `?` is any one character. Iterative, backtracking only to the last `*`.
+fn match_pattern(pattern: &[u8], string: &[u8]) -> bool {
+    let (mut p, mut s) = (0, 0);
+
+    // (index of the last `*` in pattern, index in string it resumes from)
+    let mut star = None;
+
+    while s < string.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, s));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == string[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match star {
+                Some((star_p, star_s)) => {
+                    // Let the `*` eat one more character.
+                    star = Some((star_p, star_s + 1));
+                    p = star_p + 1;
+                    s = star_s + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
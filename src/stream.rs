@@ -0,0 +1,377 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use std::io::{self, Read, Write};
+
+use zeroize::Zeroize;
+
+use crate::{size::SIZE_16, symmetric, Error, ErrorKind, Result};
+
+/// Plain bytes per segment. 64 KiB.
+pub const SEGMENT_SIZE: usize = 65536;
+
+/// Authentication tag bytes appended to every segment.
+pub const TAG_SIZE: usize = SIZE_16;
+
+// Nonce layout: prefix || counter (32-bit big endian) || last flag (1-byte).
+const COUNTER_SIZE: usize = 4;
+
+const LAST_FLAG_SIZE: usize = 1;
+
+/// AEAD used for each segment of the stream.
+pub use crate::symmetric::AeadAlgorithm as Algorithm;
+
+/// Nonce prefix length in bytes. It is written at the head of the stream.
+pub fn nonce_prefix_len(algorithm: Algorithm) -> usize {
+    algorithm.nonce_len() - COUNTER_SIZE - LAST_FLAG_SIZE
+}
+
+struct Segmenter {
+    algorithm: Algorithm,
+    key: Vec<u8>,
+    nonce_prefix: Vec<u8>,
+    counter: u32,
+    exhausted: bool,
+}
+
+impl Drop for Segmenter {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl Segmenter {
+    fn new(algorithm: Algorithm, key: &[u8], nonce_prefix: Vec<u8>) -> Result<Self> {
+        if key.len() != algorithm.key_len() {
+            Err(Error::new(
+                ErrorKind::InvalidKeyLength,
+                format!("the key length must be {}-byte.", algorithm.key_len()),
+            ))?
+        }
+
+        Ok(Self {
+            algorithm,
+            key: key.to_vec(),
+            nonce_prefix,
+            counter: 0,
+            exhausted: false,
+        })
+    }
+
+    fn next_nonce(&mut self, last: bool) -> Result<Vec<u8>> {
+        if self.exhausted {
+            Err(Error::new(
+                ErrorKind::Other,
+                "the stream has already been finished.".to_owned(),
+            ))?
+        }
+
+        let mut nonce = self.nonce_prefix.clone();
+
+        nonce.extend_from_slice(&self.counter.to_be_bytes());
+
+        nonce.push(last as u8);
+
+        if last {
+            self.exhausted = true;
+        } else {
+            self.counter = self.counter.checked_add(1).ok_or(Error::new(
+                ErrorKind::Other,
+                "the stream segment counter overflowed.".to_owned(),
+            ))?;
+        }
+
+        Ok(nonce)
+    }
+
+    fn seal(&mut self, buffer: &mut Vec<u8>, last: bool) -> Result<()> {
+        let nonce = self.next_nonce(last)?;
+
+        symmetric::encrypt(self.algorithm, &self.key, &nonce, &[], buffer)
+    }
+
+    fn open(&mut self, buffer: &mut Vec<u8>, last: bool) -> Result<()> {
+        let nonce = self.next_nonce(last)?;
+
+        symmetric::decrypt(self.algorithm, &self.key, &nonce, &[], buffer)
+    }
+}
+
+/// STREAM Encryptor.
+///
+/// The plain bytes written to it are split into 64 KiB segments and each segment is encrypted with its own nonce (prefix || counter || last flag).
+///
+/// The stream is `nonce prefix || segment || ... || last segment`. The last segment is always present, possibly empty.
+///
+/// You must call `finish`. If it is dropped without `finish`, the stream is truncated and can not be decrypted.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+///
+/// let key = xck::rand::gen_32();
+///
+/// let mut encryptor = xck::stream::StreamEncryptor::new(
+///     xck::stream::Algorithm::ChaCha20Poly1305,
+///     &key,
+///     Vec::new(),
+/// )
+/// .unwrap();
+///
+/// encryptor.write_all(b"hello").unwrap();
+///
+/// let cipher = encryptor.finish().unwrap();
+///
+/// println!("{:?}",cipher);
+/// ```
+pub struct StreamEncryptor<W: Write> {
+    segmenter: Segmenter,
+    buffer: Vec<u8>,
+    writer: W,
+}
+
+impl<W: Write> StreamEncryptor<W> {
+    /// Create an encryptor with a random nonce prefix and write the prefix to writer.
+    pub fn new(algorithm: Algorithm, key: &[u8], mut writer: W) -> Result<Self> {
+        let nonce_prefix = crate::rand::gen_24()[..nonce_prefix_len(algorithm)].to_vec();
+
+        writer.write_all(&nonce_prefix)?;
+
+        Self::with_nonce_prefix(algorithm, key, nonce_prefix, writer)
+    }
+
+    // The nonce prefix is not written to writer.
+    pub(crate) fn with_nonce_prefix(
+        algorithm: Algorithm,
+        key: &[u8],
+        nonce_prefix: Vec<u8>,
+        writer: W,
+    ) -> Result<Self> {
+        Ok(Self {
+            segmenter: Segmenter::new(algorithm, key, nonce_prefix)?,
+            buffer: Vec::with_capacity(SEGMENT_SIZE + TAG_SIZE),
+            writer,
+        })
+    }
+
+    /// Encrypt the remaining bytes as the last segment and return the writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.segmenter.seal(&mut self.buffer, true)?;
+
+        self.writer.write_all(&self.buffer)?;
+
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    // A segment is only emitted once more bytes follow it, so the last segment is always left for `finish`.
+    fn flush_segments(&mut self) -> Result<()> {
+        while self.buffer.len() > SEGMENT_SIZE {
+            let rest = self.buffer.split_off(SEGMENT_SIZE);
+
+            self.segmenter.seal(&mut self.buffer, false)?;
+
+            self.writer.write_all(&self.buffer)?;
+
+            self.buffer = rest;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for StreamEncryptor<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+
+        self.flush_segments().map_err(io::Error::other)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// STREAM Decryptor.
+///
+/// Reads a stream made by `StreamEncryptor` and returns the plain bytes.
+///
+/// An error is returned if a segment has been modified, reordered or removed, or if the stream is truncated.
+///
+/// # Example
+/// ```
+/// use std::io::{Read, Write};
+///
+/// let key = xck::rand::gen_32();
+///
+/// let mut encryptor = xck::stream::StreamEncryptor::new(
+///     xck::stream::Algorithm::ChaCha20Poly1305,
+///     &key,
+///     Vec::new(),
+/// )
+/// .unwrap();
+///
+/// encryptor.write_all(b"hello").unwrap();
+///
+/// let cipher = encryptor.finish().unwrap();
+///
+/// let mut decryptor = xck::stream::StreamDecryptor::new(
+///     xck::stream::Algorithm::ChaCha20Poly1305,
+///     &key,
+///     cipher.as_slice(),
+/// )
+/// .unwrap();
+///
+/// let mut plain = Vec::new();
+///
+/// decryptor.read_to_end(&mut plain).unwrap();
+///
+/// println!("{:?}",plain);
+/// ```
+pub struct StreamDecryptor<R: Read> {
+    segmenter: Segmenter,
+    reader: R,
+    buffer: Vec<u8>,
+    plain: Vec<u8>,
+    position: usize,
+}
+
+impl<R: Read> StreamDecryptor<R> {
+    /// Create a decryptor. The nonce prefix is read from the head of reader.
+    pub fn new(algorithm: Algorithm, key: &[u8], mut reader: R) -> Result<Self> {
+        let mut nonce_prefix = alloc::vec![0u8; nonce_prefix_len(algorithm)];
+
+        reader.read_exact(&mut nonce_prefix)?;
+
+        Self::with_nonce_prefix(algorithm, key, nonce_prefix, reader)
+    }
+
+    // The nonce prefix is not read from reader.
+    pub(crate) fn with_nonce_prefix(
+        algorithm: Algorithm,
+        key: &[u8],
+        nonce_prefix: Vec<u8>,
+        reader: R,
+    ) -> Result<Self> {
+        Ok(Self {
+            segmenter: Segmenter::new(algorithm, key, nonce_prefix)?,
+            reader,
+            buffer: Vec::with_capacity(SEGMENT_SIZE + TAG_SIZE + 1),
+            plain: Vec::new(),
+            position: 0,
+        })
+    }
+
+    fn next_segment(&mut self) -> Result<()> {
+        // Read one byte past the segment to know whether it is the last one.
+        while self.buffer.len() <= SEGMENT_SIZE + TAG_SIZE {
+            let len = self.buffer.len();
+
+            self.buffer.resize(SEGMENT_SIZE + TAG_SIZE + 1, 0);
+
+            let n = match self.reader.read(&mut self.buffer[len..]) {
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => 0,
+                Err(err) => Err(err)?,
+                Ok(0) => {
+                    self.buffer.truncate(len);
+                    break;
+                }
+                Ok(n) => n,
+            };
+
+            self.buffer.truncate(len + n);
+        }
+
+        let last = self.buffer.len() <= SEGMENT_SIZE + TAG_SIZE;
+
+        if last && self.buffer.len() < TAG_SIZE {
+            Err(Error::new(
+                ErrorKind::InvalidEncoding,
+                "the stream is truncated.".to_owned(),
+            ))?
+        }
+
+        // Only an empty stream ends with an empty segment.
+        if last && self.buffer.len() == TAG_SIZE && self.segmenter.counter > 0 {
+            Err(Error::new(
+                ErrorKind::InvalidEncoding,
+                "the last segment is empty.".to_owned(),
+            ))?
+        }
+
+        let rest = if last {
+            Vec::new()
+        } else {
+            self.buffer.split_off(SEGMENT_SIZE + TAG_SIZE)
+        };
+
+        self.segmenter.open(&mut self.buffer, last)?;
+
+        self.plain = core::mem::replace(&mut self.buffer, rest);
+
+        self.position = 0;
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for StreamDecryptor<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.plain.len() {
+            if self.segmenter.exhausted {
+                return Ok(0);
+            }
+
+            self.next_segment()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        }
+
+        let len = buf.len().min(self.plain.len() - self.position);
+
+        buf[..len].copy_from_slice(&self.plain[self.position..self.position + len]);
+
+        self.position += len;
+
+        Ok(len)
+    }
+}
+
+/// STREAM Encrypt.
+///
+/// Encrypts everything from reader and writes the stream to writer.
+pub fn encrypt(
+    algorithm: Algorithm,
+    key: &[u8],
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    let mut encryptor = StreamEncryptor::new(algorithm, key, writer)?;
+
+    io::copy(reader, &mut encryptor)?;
+
+    encryptor.finish()?;
+
+    Ok(())
+}
+
+/// STREAM Decrypt.
+///
+/// Decrypts the stream from reader and writes the plain bytes to writer.
+///
+/// Plain bytes of the verified segments may already have been written when an error is returned.
+pub fn decrypt(
+    algorithm: Algorithm,
+    key: &[u8],
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> Result<()> {
+    let mut decryptor = StreamDecryptor::new(algorithm, key, reader)?;
+
+    io::copy(&mut decryptor, writer)?;
+
+    Ok(())
+}
@@ -7,11 +7,15 @@ use alloc::vec::Vec;
 #[cfg(feature = "alloc")]
 use aead::{Aead, Payload};
 
-use aead::{AeadInPlace,Buffer,KeyInit};
+use aead::{AeadInPlace, Buffer, KeyInit};
 
 use crate::{
     size::{SIZE_12, SIZE_16, SIZE_24, SIZE_32},
-    Error, Result,
+    types::{
+        Aes128GcmKey, Aes192GcmKey, Aes256GcmKey, ChaCha20Poly1305Key, Nonce12, Nonce24,
+        XChaCha20Poly1305Key,
+    },
+    Error, ErrorKind, Result,
 };
 
 use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
@@ -29,21 +33,31 @@ type Aes192Gcm = aes_gcm::AesGcm<aes_gcm::aes::Aes192, aead::consts::U12>;
 /// Message is cipher bytes.
 #[cfg(feature = "alloc")]
 pub fn aes_256_gcm_decrypt_alloc(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_12],
+    key: &Aes256GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     cipher: &[u8],
 ) -> Result<Vec<u8>> {
-    aead_decrypt_alloc(Aes256Gcm::new_from_slice(key).unwrap(), nonce, aad, cipher)
+    aead_decrypt_alloc(
+        Aes256Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        cipher,
+    )
 }
 
 pub fn aes_256_gcm_decrypt(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_12],
+    key: &Aes256GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
-    aead_decrypt(Aes256Gcm::new_from_slice(key).unwrap(), nonce, aad, buffer)
+    aead_decrypt(
+        Aes256Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        buffer,
+    )
 }
 
 /// AES 256 Encrypt
@@ -55,21 +69,31 @@ pub fn aes_256_gcm_decrypt(
 /// Message is plain bytes.
 #[cfg(feature = "alloc")]
 pub fn aes_256_gcm_encrypt_alloc(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_12],
+    key: &Aes256GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     plain: &[u8],
 ) -> Result<Vec<u8>> {
-    aead_encrypt_alloc(Aes256Gcm::new_from_slice(key).unwrap(), nonce, aad, plain)
+    aead_encrypt_alloc(
+        Aes256Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        plain,
+    )
 }
 
 pub fn aes_256_gcm_encrypt(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_12],
+    key: &Aes256GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
-    aead_encrypt(Aes256Gcm::new_from_slice(key).unwrap(), nonce, aad, buffer)
+    aead_encrypt(
+        Aes256Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        buffer,
+    )
 }
 
 /// AES 192 Decrypt
@@ -81,21 +105,31 @@ pub fn aes_256_gcm_encrypt(
 /// Message is cipher bytes.
 #[cfg(feature = "alloc")]
 pub fn aes_192_gcm_decrypt_alloc(
-    key: &[u8; SIZE_24],
-    nonce: &[u8; SIZE_12],
+    key: &Aes192GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     cipher: &[u8],
 ) -> Result<Vec<u8>> {
-    aead_decrypt_alloc(Aes192Gcm::new_from_slice(key).unwrap(), nonce, aad, cipher)
+    aead_decrypt_alloc(
+        Aes192Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        cipher,
+    )
 }
 
 pub fn aes_192_gcm_decrypt(
-    key: &[u8; SIZE_24],
-    nonce: &[u8; SIZE_12],
+    key: &Aes192GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
-    aead_decrypt(Aes192Gcm::new_from_slice(key).unwrap(), nonce, aad, buffer)
+    aead_decrypt(
+        Aes192Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        buffer,
+    )
 }
 
 /// AES 192 Encrypt
@@ -107,21 +141,31 @@ pub fn aes_192_gcm_decrypt(
 /// Message is plain bytes.
 #[cfg(feature = "alloc")]
 pub fn aes_192_gcm_encrypt_alloc(
-    key: &[u8; SIZE_24],
-    nonce: &[u8; SIZE_12],
+    key: &Aes192GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     plain: &[u8],
 ) -> Result<Vec<u8>> {
-    aead_encrypt_alloc(Aes192Gcm::new_from_slice(key).unwrap(), nonce, aad, plain)
+    aead_encrypt_alloc(
+        Aes192Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        plain,
+    )
 }
 
 pub fn aes_192_gcm_encrypt(
-    key: &[u8; SIZE_24],
-    nonce: &[u8; SIZE_12],
+    key: &Aes192GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
-    aead_encrypt(Aes192Gcm::new_from_slice(key).unwrap(), nonce, aad, buffer)
+    aead_encrypt(
+        Aes192Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        buffer,
+    )
 }
 
 /// AES 128 Decrypt
@@ -133,21 +177,31 @@ pub fn aes_192_gcm_encrypt(
 /// Message is cipher bytes.
 #[cfg(feature = "alloc")]
 pub fn aes_128_gcm_decrypt_alloc(
-    key: &[u8; SIZE_16],
-    nonce: &[u8; SIZE_12],
+    key: &Aes128GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     cipher: &[u8],
 ) -> Result<Vec<u8>> {
-    aead_decrypt_alloc(Aes128Gcm::new_from_slice(key).unwrap(), nonce, aad, cipher)
+    aead_decrypt_alloc(
+        Aes128Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        cipher,
+    )
 }
 
 pub fn aes_128_gcm_decrypt(
-    key: &[u8; SIZE_16],
-    nonce: &[u8; SIZE_12],
+    key: &Aes128GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
-    aead_decrypt(Aes128Gcm::new_from_slice(key).unwrap(), nonce, aad, buffer)
+    aead_decrypt(
+        Aes128Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        buffer,
+    )
 }
 
 /// AES 128 Encrypt
@@ -159,21 +213,31 @@ pub fn aes_128_gcm_decrypt(
 /// Message is plain bytes.
 #[cfg(feature = "alloc")]
 pub fn aes_128_gcm_encrypt_alloc(
-    key: &[u8; SIZE_16],
-    nonce: &[u8; SIZE_12],
+    key: &Aes128GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     plain: &[u8],
 ) -> Result<Vec<u8>> {
-    aead_encrypt_alloc(Aes128Gcm::new_from_slice(key).unwrap(), nonce, aad, plain)
+    aead_encrypt_alloc(
+        Aes128Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        plain,
+    )
 }
 
 pub fn aes_128_gcm_encrypt(
-    key: &[u8; SIZE_16],
-    nonce: &[u8; SIZE_12],
+    key: &Aes128GcmKey,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
-    aead_encrypt(Aes128Gcm::new_from_slice(key).unwrap(), nonce, aad, buffer)
+    aead_encrypt(
+        Aes128Gcm::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
+        aad,
+        buffer,
+    )
 }
 
 /// XChaCha20 Poly1305 Decrypt
@@ -185,28 +249,28 @@ pub fn aes_128_gcm_encrypt(
 /// Message is cipher bytes.
 #[cfg(feature = "alloc")]
 pub fn xchacha20_poly1305_decrypt_alloc(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_24],
+    key: &XChaCha20Poly1305Key,
+    nonce: &Nonce24,
     aad: &[u8],
     cipher: &[u8],
 ) -> Result<Vec<u8>> {
     aead_decrypt_alloc(
-        XChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        XChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         cipher,
     )
 }
 
 pub fn xchacha20_poly1305_decrypt(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_24],
+    key: &XChaCha20Poly1305Key,
+    nonce: &Nonce24,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
     aead_decrypt(
-        XChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        XChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         buffer,
     )
@@ -221,28 +285,28 @@ pub fn xchacha20_poly1305_decrypt(
 /// Message is plain bytes.
 #[cfg(feature = "alloc")]
 pub fn xchacha20_poly1305_encrypt_alloc(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_24],
+    key: &XChaCha20Poly1305Key,
+    nonce: &Nonce24,
     aad: &[u8],
     plain: &[u8],
 ) -> Result<Vec<u8>> {
     aead_encrypt_alloc(
-        XChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        XChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         plain,
     )
 }
 
 pub fn xchacha20_poly1305_encrypt(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_24],
+    key: &XChaCha20Poly1305Key,
+    nonce: &Nonce24,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
     aead_encrypt(
-        XChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        XChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         buffer,
     )
@@ -257,28 +321,28 @@ pub fn xchacha20_poly1305_encrypt(
 /// Message is cipher bytes.
 #[cfg(feature = "alloc")]
 pub fn chacha20_poly1305_decrypt_alloc(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_12],
+    key: &ChaCha20Poly1305Key,
+    nonce: &Nonce12,
     aad: &[u8],
     cipher: &[u8],
 ) -> Result<Vec<u8>> {
     aead_decrypt_alloc(
-        ChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        ChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         cipher,
     )
 }
 
 pub fn chacha20_poly1305_decrypt(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_24],
+    key: &ChaCha20Poly1305Key,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
     aead_decrypt(
-        ChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        ChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         buffer,
     )
@@ -293,44 +357,258 @@ pub fn chacha20_poly1305_decrypt(
 /// Message is plain bytes.
 #[cfg(feature = "alloc")]
 pub fn chacha20_poly1305_encrypt_alloc(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_12],
+    key: &ChaCha20Poly1305Key,
+    nonce: &Nonce12,
     aad: &[u8],
     plain: &[u8],
 ) -> Result<Vec<u8>> {
     aead_encrypt_alloc(
-        ChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        ChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         plain,
     )
 }
 
 pub fn chacha20_poly1305_encrypt(
-    key: &[u8; SIZE_32],
-    nonce: &[u8; SIZE_24],
+    key: &ChaCha20Poly1305Key,
+    nonce: &Nonce12,
     aad: &[u8],
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
     aead_encrypt(
-        ChaCha20Poly1305::new_from_slice(key).unwrap(),
-        nonce,
+        ChaCha20Poly1305::new_from_slice(key.as_bytes()).unwrap(),
+        nonce.as_bytes(),
         aad,
         buffer,
     )
 }
 
+/// AEAD algorithms supported by xck.
+///
+/// Parse from the names `aes-128-gcm`, `aes-192-gcm`, `aes-256-gcm`, `chacha20-poly1305` and `xchacha20-poly1305`.
+///
+/// # Example
+/// ```
+/// let algorithm: xck::symmetric::AeadAlgorithm = "xchacha20-poly1305".parse().unwrap();
+///
+/// let key = xck::rand::gen_32();
+///
+/// let nonce = xck::rand::gen_24();
+///
+/// let mut buffer = b"hello".to_vec();
+///
+/// xck::symmetric::encrypt(algorithm, &key, &nonce, &[], &mut buffer).unwrap();
+///
+/// xck::symmetric::decrypt(algorithm, &key, &nonce, &[], &mut buffer).unwrap();
+///
+/// assert_eq!(buffer, b"hello");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AeadAlgorithm {
+    Aes128Gcm,
+    Aes192Gcm,
+    Aes256Gcm,
+    ChaCha20Poly1305,
+    XChaCha20Poly1305,
+}
+
+impl AeadAlgorithm {
+    /// All algorithms.
+    pub const ALL: [Self; 5] = [
+        Self::Aes128Gcm,
+        Self::Aes192Gcm,
+        Self::Aes256Gcm,
+        Self::ChaCha20Poly1305,
+        Self::XChaCha20Poly1305,
+    ];
+
+    /// Name of the algorithm.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Aes128Gcm => "aes-128-gcm",
+            Self::Aes192Gcm => "aes-192-gcm",
+            Self::Aes256Gcm => "aes-256-gcm",
+            Self::ChaCha20Poly1305 => "chacha20-poly1305",
+            Self::XChaCha20Poly1305 => "xchacha20-poly1305",
+        }
+    }
+
+    /// Key length in bytes.
+    pub fn key_len(&self) -> usize {
+        match self {
+            Self::Aes128Gcm => SIZE_16,
+            Self::Aes192Gcm => SIZE_24,
+            Self::Aes256Gcm | Self::ChaCha20Poly1305 | Self::XChaCha20Poly1305 => SIZE_32,
+        }
+    }
+
+    /// Nonce length in bytes.
+    pub fn nonce_len(&self) -> usize {
+        match self {
+            Self::XChaCha20Poly1305 => SIZE_24,
+            _ => SIZE_12,
+        }
+    }
+
+    /// Authentication tag length in bytes.
+    pub fn tag_len(&self) -> usize {
+        SIZE_16
+    }
+}
+
+impl core::str::FromStr for AeadAlgorithm {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+            .ok_or(Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported aead algorithm: {name}."),
+            ))
+    }
+}
+
+impl core::fmt::Display for AeadAlgorithm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// AEAD Decrypt
+///
+/// Dispatches to the function of algorithm. Key and nonce must have the lengths of algorithm.
+///
+/// Message is cipher bytes.
+#[cfg(feature = "alloc")]
+pub fn decrypt_alloc(
+    algorithm: AeadAlgorithm,
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    cipher: &[u8],
+) -> Result<Vec<u8>> {
+    match algorithm {
+        AeadAlgorithm::Aes128Gcm => {
+            aes_128_gcm_decrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, cipher)
+        }
+        AeadAlgorithm::Aes192Gcm => {
+            aes_192_gcm_decrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, cipher)
+        }
+        AeadAlgorithm::Aes256Gcm => {
+            aes_256_gcm_decrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, cipher)
+        }
+        AeadAlgorithm::ChaCha20Poly1305 => {
+            chacha20_poly1305_decrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, cipher)
+        }
+        AeadAlgorithm::XChaCha20Poly1305 => {
+            xchacha20_poly1305_decrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, cipher)
+        }
+    }
+}
+
+/// AEAD Decrypt in place.
+///
+/// Dispatches to the function of algorithm. Key and nonce must have the lengths of algorithm.
+pub fn decrypt(
+    algorithm: AeadAlgorithm,
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    buffer: &mut dyn Buffer,
+) -> Result<()> {
+    match algorithm {
+        AeadAlgorithm::Aes128Gcm => {
+            aes_128_gcm_decrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::Aes192Gcm => {
+            aes_192_gcm_decrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::Aes256Gcm => {
+            aes_256_gcm_decrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::ChaCha20Poly1305 => {
+            chacha20_poly1305_decrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::XChaCha20Poly1305 => {
+            xchacha20_poly1305_decrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+    }
+}
+
+/// AEAD Encrypt
+///
+/// Dispatches to the function of algorithm. Key and nonce must have the lengths of algorithm.
+///
+/// Message is plain bytes.
+#[cfg(feature = "alloc")]
+pub fn encrypt_alloc(
+    algorithm: AeadAlgorithm,
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    plain: &[u8],
+) -> Result<Vec<u8>> {
+    match algorithm {
+        AeadAlgorithm::Aes128Gcm => {
+            aes_128_gcm_encrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, plain)
+        }
+        AeadAlgorithm::Aes192Gcm => {
+            aes_192_gcm_encrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, plain)
+        }
+        AeadAlgorithm::Aes256Gcm => {
+            aes_256_gcm_encrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, plain)
+        }
+        AeadAlgorithm::ChaCha20Poly1305 => {
+            chacha20_poly1305_encrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, plain)
+        }
+        AeadAlgorithm::XChaCha20Poly1305 => {
+            xchacha20_poly1305_encrypt_alloc(&key.try_into()?, &nonce.try_into()?, aad, plain)
+        }
+    }
+}
+
+/// AEAD Encrypt in place.
+///
+/// Dispatches to the function of algorithm. Key and nonce must have the lengths of algorithm.
+pub fn encrypt(
+    algorithm: AeadAlgorithm,
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    buffer: &mut dyn Buffer,
+) -> Result<()> {
+    match algorithm {
+        AeadAlgorithm::Aes128Gcm => {
+            aes_128_gcm_encrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::Aes192Gcm => {
+            aes_192_gcm_encrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::Aes256Gcm => {
+            aes_256_gcm_encrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::ChaCha20Poly1305 => {
+            chacha20_poly1305_encrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+        AeadAlgorithm::XChaCha20Poly1305 => {
+            xchacha20_poly1305_encrypt(&key.try_into()?, &nonce.try_into()?, aad, buffer)
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn aead_decrypt_alloc(aead: impl Aead, nonce: &[u8], aad: &[u8], cipher: &[u8]) -> Result<Vec<u8>> {
     let plain = aead
-        .decrypt(
-            nonce.into(),
-            Payload {
-                msg: cipher,
-                aad: aad,
-            },
-        )
-        .map_err(|err| Error::new(err.to_string()))?;
+        .decrypt(nonce.into(), Payload { msg: cipher, aad })
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::AuthenticationFailed,
+                "the authentication tag does not match.".to_owned(),
+            )
+        })?;
 
     Ok(plain)
 }
@@ -338,14 +616,13 @@ fn aead_decrypt_alloc(aead: impl Aead, nonce: &[u8], aad: &[u8], cipher: &[u8])
 #[cfg(feature = "alloc")]
 fn aead_encrypt_alloc(aead: impl Aead, nonce: &[u8], aad: &[u8], plain: &[u8]) -> Result<Vec<u8>> {
     let cipher = aead
-        .encrypt(
-            nonce.into(),
-            Payload {
-                msg: plain,
-                aad: aad,
-            },
-        )
-        .map_err(|err| Error::new(err.to_string()))?;
+        .encrypt(nonce.into(), Payload { msg: plain, aad })
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidLength,
+                "the plain text is too long.".to_owned(),
+            )
+        })?;
 
     Ok(cipher)
 }
@@ -357,7 +634,12 @@ fn aead_encrypt(
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
     aead.encrypt_in_place(nonce.into(), aad, buffer)
-        .map_err(|err| Error::new(err.to_string()))
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::BufferTooSmall,
+                "the buffer is too small.".to_owned(),
+            )
+        })
 }
 
 fn aead_decrypt(
@@ -367,5 +649,10 @@ fn aead_decrypt(
     buffer: &mut dyn Buffer,
 ) -> Result<()> {
     aead.decrypt_in_place(nonce.into(), aad, buffer)
-        .map_err(|err| Error::new(err.to_string()))
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::AuthenticationFailed,
+                "the authentication tag does not match.".to_owned(),
+            )
+        })
 }
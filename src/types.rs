@@ -0,0 +1,200 @@
+use zeroize::Zeroize;
+
+use crate::{
+    size::{SIZE_12, SIZE_16, SIZE_24, SIZE_32, SIZE_64},
+    Error, ErrorKind, Result,
+};
+
+// Secret bytes. Zeroized on drop and redacted in Debug.
+macro_rules! secret_type {
+    ($(#[$meta:meta])* $name:ident, $size:ident) => {
+        $(#[$meta])*
+        #[derive(Clone)]
+        pub struct $name([u8; $size]);
+
+        impl $name {
+            pub fn from_bytes(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+
+            pub fn as_bytes(&self) -> &[u8; $size] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; $size]> for $name {
+            fn from(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = Error;
+
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                Ok(Self(bytes.try_into().map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidKeyLength,
+                        format!("the key length must be {}-byte.", $size),
+                    )
+                })?))
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(concat!(stringify!($name), "(REDACTED)"))
+            }
+        }
+
+        impl Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                self.zeroize();
+            }
+        }
+    };
+}
+
+// Public bytes.
+macro_rules! public_type {
+    ($(#[$meta:meta])* $name:ident, $size:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name([u8; $size]);
+
+        impl $name {
+            pub fn from_bytes(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+
+            pub fn as_bytes(&self) -> &[u8; $size] {
+                &self.0
+            }
+
+            pub fn to_bytes(&self) -> [u8; $size] {
+                self.0
+            }
+        }
+
+        impl From<[u8; $size]> for $name {
+            fn from(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $size] {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = Error;
+
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                Ok(Self(bytes.try_into().map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidLength,
+                        format!("the length must be {}-byte.", $size),
+                    )
+                })?))
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+    };
+}
+
+secret_type!(
+    /// Ed25519 private-key. 32-byte.
+    Ed25519SecretKey,
+    SIZE_32
+);
+
+public_type!(
+    /// Ed25519 public-key. 32-byte.
+    Ed25519PublicKey,
+    SIZE_32
+);
+
+public_type!(
+    /// Ed25519 signature. 64-byte.
+    Ed25519Signature,
+    SIZE_64
+);
+
+secret_type!(
+    /// X25519 private-key. 32-byte.
+    X25519SecretKey,
+    SIZE_32
+);
+
+public_type!(
+    /// X25519 public-key. 32-byte.
+    X25519PublicKey,
+    SIZE_32
+);
+
+secret_type!(
+    /// X25519 shared secret. 32-byte.
+    X25519SharedSecret,
+    SIZE_32
+);
+
+secret_type!(
+    /// AES-128-GCM key. 16-byte.
+    Aes128GcmKey,
+    SIZE_16
+);
+
+secret_type!(
+    /// AES-192-GCM key. 24-byte.
+    Aes192GcmKey,
+    SIZE_24
+);
+
+secret_type!(
+    /// AES-256-GCM key. 32-byte.
+    Aes256GcmKey,
+    SIZE_32
+);
+
+secret_type!(
+    /// ChaCha20-Poly1305 key. 32-byte.
+    ChaCha20Poly1305Key,
+    SIZE_32
+);
+
+secret_type!(
+    /// XChaCha20-Poly1305 key. 32-byte.
+    XChaCha20Poly1305Key,
+    SIZE_32
+);
+
+public_type!(
+    /// 12-byte nonce. AES-GCM and ChaCha20-Poly1305.
+    Nonce12,
+    SIZE_12
+);
+
+public_type!(
+    /// 24-byte nonce. XChaCha20-Poly1305.
+    Nonce24,
+    SIZE_24
+);
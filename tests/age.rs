@@ -0,0 +1,166 @@
+// cargo test --features="alloc" --package xck --test age -- --nocapture
+
+// Made by the reference implementation (rage). It has a grease stanza of an unknown type.
+#[cfg(feature = "alloc")]
+const TEST_IDENTITY: &str =
+    "AGE-SECRET-KEY-1MHP5S5ZWQSHJ05Y8XRKLYNY8QVJVSJUVXMQFVF9NAJQHEKLZUDNQWA5W7Y";
+
+#[cfg(feature = "alloc")]
+const TEST_RECIPIENT: &str = "age1p3l5amy69gkqqueltnl90w0fcut8ueyehkd02qjc8prqrlzj452q9wwjfu";
+
+#[cfg(feature = "alloc")]
+const TEST_ARMORED: &str = "-----BEGIN AGE ENCRYPTED FILE-----
+YWdlLWVuY3J5cHRpb24ub3JnL3YxCi0+IFgyNTUxOSArc2ZaWDZZcFBHMkFidWJ6
+Mkovb3hxRnRCcVIwZzVPYjZubUNrczdLY3hnClJZdHhSL01oWFlnMHA0UEJMQ1pt
+MnZFUjFVbVFmWkh1QmxpZ2k5TDNkZTgKLT4gYXJGOFEtZ3JlYXNlCkRlTEhhNncw
+YXZNCi0tLSB0WmJRSXd6N3g4aHpsUUhJOU4rbWxRSFBubWZXVEY3eU41cU9STmRZ
+a0I4Crjc49wH6lpg4l5fFENZKmf21B6FmXF1TfsMKrrsIFwLZff6Ijtbf70P
+-----END AGE ENCRYPTED FILE-----
+";
+
+#[cfg(feature = "alloc")]
+fn age_decrypt(identity: &xck::types::X25519SecretKey, file: &[u8]) -> xck::Result<Vec<u8>> {
+    let mut plain = Vec::new();
+
+    xck::age::decrypt(std::slice::from_ref(identity), &mut &file[..], &mut plain)?;
+
+    Ok(plain)
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn age_reference_file() {
+    let identities = xck::age::parse_identities(&format!(
+        "# public key: {TEST_RECIPIENT}\n{TEST_IDENTITY}\n"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        xck::age::encode_recipient(&xck::asymmetric::x25519_gen_public_key(&identities[0])),
+        TEST_RECIPIENT
+    );
+
+    assert_eq!(xck::age::encode_identity(&identities[0]), TEST_IDENTITY);
+
+    assert!(xck::age::is_armored(TEST_ARMORED.as_bytes()));
+
+    let file = xck::age::dearmor(TEST_ARMORED.as_bytes()).unwrap();
+
+    assert_eq!(xck::age::armor(&file).unwrap(), TEST_ARMORED);
+
+    assert_eq!(age_decrypt(&identities[0], &file).unwrap(), b"hello age");
+
+    assert_eq!(
+        age_decrypt(&xck::asymmetric::x25519_gen_private_key(), &file)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn age_round_trip() {
+    let (private_key, public_key) = xck::asymmetric::x25519_gen_keypair();
+
+    let (other_private_key, other_public_key) = xck::asymmetric::x25519_gen_keypair();
+
+    for len in [
+        0,
+        5,
+        xck::stream::SEGMENT_SIZE,
+        xck::stream::SEGMENT_SIZE * 2 + 1,
+    ] {
+        let plain = vec![7u8; len];
+
+        let mut file = Vec::new();
+
+        xck::age::encrypt(
+            &[public_key, other_public_key],
+            &mut plain.as_slice(),
+            &mut file,
+        )
+        .unwrap();
+
+        assert_eq!(age_decrypt(&private_key, &file).unwrap(), plain);
+
+        assert_eq!(age_decrypt(&other_private_key, &file).unwrap(), plain);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn age_passphrase() {
+    let mut file = Vec::new();
+
+    xck::age::encrypt_with_passphrase(b"passphrase", 10, &mut &b"hello"[..], &mut file).unwrap();
+
+    let mut plain = Vec::new();
+
+    xck::age::decrypt_with_passphrase(b"passphrase", &mut file.as_slice(), &mut plain).unwrap();
+
+    assert_eq!(plain, b"hello");
+
+    assert_eq!(
+        xck::age::decrypt_with_passphrase(b"wrong", &mut file.as_slice(), &mut Vec::new())
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    assert_eq!(
+        age_decrypt(&xck::asymmetric::x25519_gen_private_key(), &file)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::Unsupported
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn age_tamper() {
+    let (private_key, public_key) = xck::asymmetric::x25519_gen_keypair();
+
+    let mut file = Vec::new();
+
+    xck::age::encrypt(&[public_key], &mut &b"hello"[..], &mut file).unwrap();
+
+    // A modified stanza.
+    let mut tampered = file.clone();
+
+    let position = tampered.iter().position(|&byte| byte == b'X').unwrap();
+
+    tampered[position] = b'Y';
+
+    assert!(age_decrypt(&private_key, &tampered).is_err());
+
+    // The payload.
+    let mut tampered = file.clone();
+
+    let len = tampered.len();
+
+    tampered[len - 1] ^= 1;
+
+    assert!(age_decrypt(&private_key, &tampered).is_err());
+
+    assert!(age_decrypt(&private_key, &file[..file.len() - 1]).is_err());
+
+    assert_eq!(
+        age_decrypt(&private_key, b"hello world")
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidEncoding
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn age_decode_invalid() {
+    assert!(xck::age::decode_recipient(TEST_IDENTITY).is_err());
+
+    assert!(xck::age::decode_identity(TEST_RECIPIENT).is_err());
+
+    assert!(xck::age::decode_recipient(&TEST_RECIPIENT.replace('p', "q")).is_err());
+
+    assert!(xck::age::encrypt(&[], &mut &b"hello"[..], &mut Vec::new()).is_err());
+}
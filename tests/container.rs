@@ -0,0 +1,252 @@
+// cargo test --features="alloc" --package xck --test container -- --nocapture
+
+#[test]
+#[cfg(feature = "alloc")]
+fn container_seal_open() {
+    const ALGORITHMS: [xck::container::Algorithm; 5] = [
+        xck::container::Algorithm::Aes128Gcm,
+        xck::container::Algorithm::Aes192Gcm,
+        xck::container::Algorithm::Aes256Gcm,
+        xck::container::Algorithm::ChaCha20Poly1305,
+        xck::container::Algorithm::XChaCha20Poly1305,
+    ];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    let key = xck::rand::gen_32();
+
+    for algorithm in ALGORITHMS {
+        let key = &key[..algorithm.key_len()];
+
+        let sealed = xck::container::seal(algorithm, key, b"aad", &TEST_MESSAGE).unwrap();
+
+        let (header, _) = xck::container::Header::from_bytes(&sealed).unwrap();
+
+        assert_eq!(header.algorithm, algorithm);
+
+        assert_eq!(
+            xck::container::open(key, b"aad", &sealed).unwrap(),
+            TEST_MESSAGE
+        );
+
+        assert!(xck::container::open(key, &[], &sealed).is_err());
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn container_open_version_1() {
+    const TEST_KEY: [u8; 32] = [
+        57, 175, 86, 245, 102, 95, 243, 137, 254, 235, 187, 7, 87, 88, 175, 190, 102, 82, 188, 163,
+        54, 51, 85, 130, 172, 177, 0, 252, 130, 32, 174, 81,
+    ];
+
+    const TEST_NONCE: [u8; 24] = [
+        38, 51, 16, 243, 54, 82, 44, 250, 194, 172, 143, 105, 171, 148, 115, 75, 219, 222, 138,
+        150, 202, 46, 215, 4,
+    ];
+
+    // magic || version || algorithm || kdf || nonce
+    const TEST_HEADER: [u8; 31] = [
+        88, 67, 75, 0, 1, 5, 0, 38, 51, 16, 243, 54, 82, 44, 250, 194, 172, 143, 105, 171, 148,
+        115, 75, 219, 222, 138, 150, 202, 46, 215, 4,
+    ];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    let mut sealed = TEST_HEADER.to_vec();
+
+    sealed.extend(
+        xck::symmetric::xchacha20_poly1305_encrypt_alloc(
+            &xck::types::XChaCha20Poly1305Key::from(TEST_KEY),
+            &xck::types::Nonce24::from(TEST_NONCE),
+            &TEST_HEADER,
+            &TEST_MESSAGE,
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(
+        xck::container::open(&TEST_KEY, &[], &sealed).unwrap(),
+        TEST_MESSAGE
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn container_header_to_bytes() {
+    let header = xck::container::Header {
+        algorithm: xck::container::Algorithm::Aes256Gcm,
+        kdf: Some(xck::container::Argon2idParams {
+            salt: [1u8; 16],
+            memory_cost: 19456,
+            time_cost: 2,
+            parallelism: 1,
+        }),
+        nonce: vec![2u8; 12],
+    };
+
+    let bytes = header.to_bytes();
+
+    assert_eq!(bytes.len(), 4 + 3 + 28 + 12);
+
+    assert_eq!(
+        xck::container::Header::from_bytes(&bytes).unwrap(),
+        (header, bytes.len())
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn container_reject_header() {
+    let key = xck::rand::gen_32();
+
+    let sealed = xck::container::seal(
+        xck::container::Algorithm::ChaCha20Poly1305,
+        &key,
+        &[],
+        b"hello",
+    )
+    .unwrap();
+
+    // magic
+    let mut bytes = sealed.clone();
+    bytes[0] = 0;
+    assert!(xck::container::open(&key, &[], &bytes).is_err());
+
+    // version
+    let mut bytes = sealed.clone();
+    bytes[4] = 2;
+    assert!(xck::container::open(&key, &[], &bytes)
+        .unwrap_err()
+        .message()
+        .contains("version"));
+
+    // algorithm, still a valid id with the same key length
+    let mut bytes = sealed.clone();
+    bytes[5] = 3;
+    assert!(xck::container::open(&key, &[], &bytes).is_err());
+
+    // unknown algorithm
+    let mut bytes = sealed.clone();
+    bytes[5] = 255;
+    assert!(xck::container::open(&key, &[], &bytes).is_err());
+
+    // kdf
+    let mut bytes = sealed.clone();
+    bytes[6] = 9;
+    assert!(xck::container::open(&key, &[], &bytes).is_err());
+
+    // truncated
+    assert!(xck::container::open(&key, &[], &sealed[..10]).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn container_seal_open_with_passphrase() {
+    const TEST_PASSPHRASE: &[u8] = b"hello 0123 password";
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    let params = xck::container::Argon2idParams::new(8192, 1, 1);
+
+    let sealed = xck::container::seal_with_passphrase(
+        xck::container::Algorithm::Aes128Gcm,
+        TEST_PASSPHRASE,
+        params,
+        &[],
+        &TEST_MESSAGE,
+    )
+    .unwrap();
+
+    let (header, _) = xck::container::Header::from_bytes(&sealed).unwrap();
+
+    assert_eq!(header.kdf, Some(params));
+
+    assert_eq!(
+        xck::container::open_with_passphrase(TEST_PASSPHRASE, &[], &sealed).unwrap(),
+        TEST_MESSAGE
+    );
+
+    assert!(xck::container::open_with_passphrase(b"wrong password", &[], &sealed).is_err());
+
+    assert!(xck::container::open(&[0u8; 16], &[], &sealed).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn container_open_with_passphrase_version_1() {
+    const TEST_PASSPHRASE: &[u8] = b"hello 0123 password";
+
+    // Argon2id, salt = [1; 16], m = 8192, t = 1, p = 1, 32-byte.
+    const TEST_KEY: [u8; 32] = [
+        48, 68, 59, 56, 43, 183, 100, 120, 254, 72, 119, 49, 120, 146, 117, 156, 14, 130, 68, 78,
+        171, 185, 62, 115, 50, 134, 72, 18, 31, 20, 178, 123,
+    ];
+
+    let header = xck::container::Header {
+        algorithm: xck::container::Algorithm::ChaCha20Poly1305,
+        kdf: Some(xck::container::Argon2idParams {
+            salt: [1u8; 16],
+            memory_cost: 8192,
+            time_cost: 1,
+            parallelism: 1,
+        }),
+        nonce: vec![2u8; 12],
+    };
+
+    let mut sealed = header.to_bytes();
+
+    let cipher = xck::symmetric::chacha20_poly1305_encrypt_alloc(
+        &xck::types::ChaCha20Poly1305Key::from(TEST_KEY),
+        &xck::types::Nonce12::from([2u8; 12]),
+        &sealed,
+        b"hello",
+    )
+    .unwrap();
+
+    sealed.extend(cipher);
+
+    assert_eq!(
+        xck::container::open_with_passphrase(TEST_PASSPHRASE, &[], &sealed).unwrap(),
+        b"hello"
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn container_open_with_passphrase_params_too_high() {
+    let params = [
+        (u32::MAX, 1, 1),
+        (8192, u32::MAX, 1),
+        (8192, 1, 0xFFFFFF),
+        (xck::container::MAX_MEMORY_COST + 1, 1, 1),
+    ];
+
+    for (memory_cost, time_cost, parallelism) in params {
+        let header = xck::container::Header {
+            algorithm: xck::container::Algorithm::ChaCha20Poly1305,
+            kdf: Some(xck::container::Argon2idParams {
+                salt: [1u8; 16],
+                memory_cost,
+                time_cost,
+                parallelism,
+            }),
+            nonce: vec![2u8; 12],
+        };
+
+        let mut sealed = header.to_bytes();
+
+        sealed.extend([0u8; 21]);
+
+        assert_eq!(
+            xck::container::open_with_passphrase(b"hello 0123 password", &[], &sealed)
+                .unwrap_err()
+                .kind(),
+            xck::ErrorKind::InvalidParameter
+        );
+    }
+}
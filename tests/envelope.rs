@@ -0,0 +1,185 @@
+// cargo test --features="alloc" --package xck --test envelope -- --nocapture
+
+#[test]
+#[cfg(feature = "alloc")]
+fn envelope_sign_verify() {
+    const ALGORITHMS: [(xck::envelope::Algorithm, &[u8]); 4] = [
+        (xck::envelope::Algorithm::Ed25519, b""),
+        (xck::envelope::Algorithm::Ed25519ctx, b"context"),
+        (xck::envelope::Algorithm::Ed25519ph, b""),
+        (xck::envelope::Algorithm::Ed25519ph, b"context"),
+    ];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    let (private_key, public_key) = xck::asymmetric::ed25519_gen_keypair();
+
+    let (_, other_public_key) = xck::asymmetric::ed25519_gen_keypair();
+
+    for (algorithm, context) in ALGORITHMS {
+        let envelope = xck::envelope::sign(
+            &private_key,
+            algorithm,
+            context,
+            &mut &TEST_MESSAGE[..],
+            1700000000,
+            "release 1.0",
+        )
+        .unwrap();
+
+        let envelope =
+            xck::envelope::Envelope::from_pem(envelope.to_pem().unwrap().as_bytes()).unwrap();
+
+        assert_eq!(envelope.algorithm, algorithm);
+
+        assert_eq!(
+            envelope.fingerprint,
+            xck::envelope::key_fingerprint(&public_key)
+        );
+
+        assert!(
+            xck::envelope::verify(&envelope, &public_key, context, &mut &TEST_MESSAGE[..]).is_ok()
+        );
+
+        assert!(
+            xck::envelope::verify(&envelope, &public_key, context, &mut &b"hellO"[..]).is_err()
+        );
+
+        assert!(
+            xck::envelope::verify(&envelope, &public_key, b"other", &mut &TEST_MESSAGE[..])
+                .is_err()
+        );
+
+        assert_eq!(
+            xck::envelope::verify(
+                &envelope,
+                &other_public_key,
+                context,
+                &mut &TEST_MESSAGE[..]
+            )
+            .unwrap_err()
+            .kind(),
+            xck::ErrorKind::AuthenticationFailed
+        );
+
+        // The metadata is signed.
+        let mut tampered = envelope.clone();
+
+        tampered.comment = "release 2.0".to_owned();
+
+        assert!(
+            xck::envelope::verify(&tampered, &public_key, context, &mut &TEST_MESSAGE[..]).is_err()
+        );
+
+        let mut tampered = envelope.clone();
+
+        tampered.timestamp += 1;
+
+        assert!(
+            xck::envelope::verify(&tampered, &public_key, context, &mut &TEST_MESSAGE[..]).is_err()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn envelope_version_1() {
+    // RFC 8032 7.1. TEST 1
+    const TEST_PRIVATE_KEY: [u8; 32] = [
+        157, 97, 177, 157, 239, 253, 90, 96, 186, 132, 74, 244, 146, 236, 44, 196, 68, 73, 197,
+        105, 123, 50, 105, 25, 112, 59, 172, 3, 28, 174, 127, 96,
+    ];
+
+    // magic || version || algorithm || fingerprint || timestamp || comment length || comment || signature
+    const TEST_ENVELOPE: [u8; 115] = [
+        88, 67, 75, 83, 1, 1, 33, 254, 49, 223, 161, 84, 162, 97, 98, 107, 248, 84, 4, 111, 210,
+        39, 27, 123, 237, 75, 106, 190, 69, 170, 88, 135, 126, 244, 127, 151, 33, 185, 0, 0, 0, 0,
+        101, 83, 241, 0, 0, 3, 120, 99, 107, 212, 161, 30, 43, 91, 25, 29, 175, 55, 244, 41, 16,
+        44, 193, 199, 50, 101, 187, 66, 163, 225, 47, 138, 121, 113, 193, 168, 124, 211, 156, 207,
+        64, 20, 174, 143, 4, 74, 136, 140, 145, 125, 70, 144, 93, 159, 6, 45, 129, 48, 130, 53,
+        166, 136, 53, 93, 57, 165, 179, 68, 206, 145, 245, 138, 12,
+    ];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    let private_key = xck::types::Ed25519SecretKey::from(TEST_PRIVATE_KEY);
+
+    let public_key = xck::asymmetric::ed25519_gen_public_key(&private_key);
+
+    let envelope = xck::envelope::sign(
+        &private_key,
+        xck::envelope::Algorithm::Ed25519,
+        &[],
+        &mut &TEST_MESSAGE[..],
+        1700000000,
+        "xck",
+    )
+    .unwrap();
+
+    assert_eq!(envelope.to_bytes().unwrap(), TEST_ENVELOPE);
+
+    let envelope = xck::envelope::Envelope::from_bytes(&TEST_ENVELOPE).unwrap();
+
+    assert_eq!(envelope.timestamp, 1700000000);
+
+    assert_eq!(envelope.comment, "xck");
+
+    assert!(xck::envelope::verify(&envelope, &public_key, &[], &mut &TEST_MESSAGE[..]).is_ok());
+
+    assert!(
+        xck::envelope::verify(&envelope, &public_key, b"context", &mut &TEST_MESSAGE[..]).is_err()
+    );
+
+    // A bare Ed25519 signature of the message is not an envelope signature.
+    let mut bare = envelope.clone();
+
+    bare.signature = xck::asymmetric::ed25519_sign(&private_key, &TEST_MESSAGE).unwrap();
+
+    assert!(xck::envelope::verify(&bare, &public_key, &[], &mut &TEST_MESSAGE[..]).is_err());
+
+    let mut bad_magic = TEST_ENVELOPE;
+
+    bad_magic[3] = 0;
+
+    assert_eq!(
+        xck::envelope::Envelope::from_bytes(&bad_magic)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidEncoding
+    );
+
+    let mut bad_version = TEST_ENVELOPE;
+
+    bad_version[4] = 2;
+
+    assert_eq!(
+        xck::envelope::Envelope::from_bytes(&bad_version)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::Unsupported
+    );
+
+    let mut bad_algorithm = TEST_ENVELOPE;
+
+    bad_algorithm[5] = 0;
+
+    assert_eq!(
+        xck::envelope::Envelope::from_bytes(&bad_algorithm)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::Unsupported
+    );
+
+    for len in [0, 47, 50, 114] {
+        assert_eq!(
+            xck::envelope::Envelope::from_bytes(&TEST_ENVELOPE[..len])
+                .unwrap_err()
+                .kind(),
+            xck::ErrorKind::InvalidEncoding
+        );
+    }
+
+    assert!(xck::envelope::Envelope::from_bytes(&[&TEST_ENVELOPE[..], &[0]].concat()).is_err());
+}
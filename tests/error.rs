@@ -0,0 +1,113 @@
+use std::error::Error as _;
+
+use xck::ErrorKind;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn authentication_failed() {
+    let key = [1u8; 32];
+
+    let mut sealed = xck::container::seal(
+        xck::container::Algorithm::ChaCha20Poly1305,
+        &key,
+        &[],
+        b"hello",
+    )
+    .unwrap();
+
+    let last = sealed.len() - 1;
+
+    sealed[last] ^= 1;
+
+    assert_eq!(
+        xck::container::open(&key, &[], &sealed).unwrap_err().kind(),
+        ErrorKind::AuthenticationFailed
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn stream_authentication_failed() {
+    let key = [1u8; 32];
+
+    let mut stream = Vec::new();
+
+    xck::stream::encrypt(
+        xck::stream::Algorithm::ChaCha20Poly1305,
+        &key,
+        &mut &b"hello"[..],
+        &mut stream,
+    )
+    .unwrap();
+
+    let last = stream.len() - 1;
+
+    stream[last] ^= 1;
+
+    let err = xck::stream::decrypt(
+        xck::stream::Algorithm::ChaCha20Poly1305,
+        &key,
+        &mut stream.as_slice(),
+        &mut Vec::new(),
+    )
+    .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::AuthenticationFailed);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn malformed_container() {
+    let key = [1u8; 32];
+
+    assert_eq!(
+        xck::container::open(&key, &[], b"NOPE").unwrap_err().kind(),
+        ErrorKind::InvalidEncoding
+    );
+
+    assert_eq!(
+        xck::container::open(&key, &[], &[88, 67, 75, 0, 9])
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Unsupported
+    );
+}
+
+#[test]
+fn pem_decode() {
+    let pem = xck::format::pem_encode("CERTIFICATE", &[0u8; 32]).unwrap();
+
+    assert_eq!(
+        xck::format::pem_decode(pem.as_bytes()).unwrap_err().kind(),
+        ErrorKind::UnsupportedLabel
+    );
+
+    let err =
+        xck::format::pem_decode(b"-----BEGIN PUBLIC KEY-----\nAAAA\n-----END PUBLIC KEY-----\n")
+            .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::InvalidKeyLength);
+
+    assert!(!err.message().is_empty());
+}
+
+#[test]
+fn source_and_display() {
+    let err = xck::format::base64_decode("!!!!").unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::InvalidEncoding);
+
+    assert!(err.source().is_some());
+
+    assert_eq!(err.to_string(), err.message());
+}
+
+#[test]
+fn invalid_key_length() {
+    assert_eq!(
+        xck::types::Aes128GcmKey::try_from([0u8; 15].as_slice())
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidKeyLength
+    );
+}
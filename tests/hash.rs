@@ -87,8 +87,545 @@ fn blake3_mac() {
         227, 50, 176, 112, 41, 42, 247, 180, 199, 34, 87, 76, 38,
     ];
 
+    assert_eq!(xck::hash::blake3_mac(&TEST_KEY, &TEST_MESSAGE), TEST_MAC);
+}
+
+#[test]
+fn argon2id() {
+    const TEST_PASSWORD: &[u8] = b"hello 0123 password";
+
+    const TEST_SALT: [u8; 16] = [1; 16];
+
+    const TEST_HASH: [u8; 32] = [
+        219, 113, 113, 240, 245, 23, 129, 70, 93, 104, 237, 158, 164, 8, 64, 200, 152, 45, 175,
+        249, 137, 22, 119, 169, 118, 15, 184, 108, 120, 181, 20, 10,
+    ];
+
     assert_eq!(
-        xck::hash::blake3_mac(&TEST_KEY, &TEST_MESSAGE),
-        TEST_MAC
+        xck::hash::password_hash::argon2id(TEST_PASSWORD, TEST_SALT).unwrap(),
+        TEST_HASH
     );
 }
+
+#[test]
+fn hash_password() {
+    const TEST_PASSWORD: &[u8] = b"hello 0123 password";
+
+    let phc =
+        xck::hash::password_hash::hash_password_with_params(TEST_PASSWORD, 8192, 1, 1).unwrap();
+
+    assert!(phc.starts_with("$argon2id$v=19$m=8192,t=1,p=1$"));
+
+    assert!(xck::hash::password_hash::verify_password(TEST_PASSWORD, &phc).is_ok());
+
+    assert!(xck::hash::password_hash::verify_password(b"wrong password", &phc).is_err());
+}
+
+#[test]
+fn verify_password() {
+    const TEST_PASSWORD: &[u8] = b"hello 0123 password";
+
+    const TEST_PHC_STRINGS: [&str; 3] = [
+        "$argon2i$v=19$m=8192,t=1,p=1$AQEBAQEBAQEBAQEBAQEBAQE$GrMcsaz6loXF1vu3ECuY2ywOFLzK1pMgU00+ZwbXFs4",
+        "$argon2d$v=19$m=8192,t=1,p=1$AQEBAQEBAQEBAQEBAQEBAQE$vz2dj0GDPD3XSeOgj0cTyTiUbpE/Wn6Ll3EzSTjzXWI",
+        "$argon2id$v=19$m=8192,t=1,p=1$AQEBAQEBAQEBAQEBAQEBAQE$Uc6s0lrAI8zvFWU+QI7yk+0NqcHUNzgbJHLh9u0wiN4",
+    ];
+
+    for phc in TEST_PHC_STRINGS {
+        assert!(xck::hash::password_hash::verify_password(TEST_PASSWORD, phc).is_ok());
+
+        assert!(xck::hash::password_hash::verify_password(b"hello", phc).is_err());
+    }
+
+    assert!(xck::hash::password_hash::verify_password(TEST_PASSWORD, "$argon2id$").is_err());
+}
+
+#[test]
+fn needs_rehash() {
+    const TEST_PHC_STRING: &str =
+        "$argon2id$v=19$m=8192,t=1,p=1$AQEBAQEBAQEBAQEBAQEBAQE$Uc6s0lrAI8zvFWU+QI7yk+0NqcHUNzgbJHLh9u0wiN4";
+
+    const TEST_ARGON2I_PHC_STRING: &str =
+        "$argon2i$v=19$m=8192,t=1,p=1$AQEBAQEBAQEBAQEBAQEBAQE$GrMcsaz6loXF1vu3ECuY2ywOFLzK1pMgU00+ZwbXFs4";
+
+    assert!(!xck::hash::password_hash::needs_rehash(TEST_PHC_STRING, 8192, 1, 1).unwrap());
+
+    assert!(xck::hash::password_hash::needs_rehash(TEST_PHC_STRING, 19456, 2, 1).unwrap());
+
+    assert!(xck::hash::password_hash::needs_rehash(TEST_ARGON2I_PHC_STRING, 8192, 1, 1).unwrap());
+}
+
+#[test]
+fn sha2_hasher() {
+    let mut sha256 = xck::hash::Sha256Hasher::new();
+
+    let mut sha512 = xck::hash::Sha512Hasher::new();
+
+    let mut sha512_256 = xck::hash::Sha512_256Hasher::new();
+
+    for chunk in [&b"hel"[..], b"", b"lo"] {
+        sha256.update(chunk);
+
+        sha512.update(chunk);
+
+        sha512_256.update(chunk);
+    }
+
+    assert_eq!(sha256.finalize(), xck::hash::sha256(b"hello"));
+
+    assert_eq!(sha512.finalize(), xck::hash::sha512(b"hello"));
+
+    assert_eq!(sha512_256.finalize(), xck::hash::sha512_256(b"hello"));
+
+    sha256.reset();
+
+    sha256.update(b"world");
+
+    assert_eq!(sha256.finalize(), xck::hash::sha256(b"world"));
+}
+
+#[test]
+fn blake3_hasher() {
+    let mut hasher = xck::hash::Blake3Hasher::new();
+
+    hasher.update(b"hel");
+
+    hasher.update(b"lo");
+
+    assert_eq!(hasher.finalize(), xck::hash::blake3(b"hello"));
+
+    let mut xof = [0u8; 100];
+
+    let mut expected = [0u8; 100];
+
+    hasher.finalize_xof(&mut xof);
+
+    xck::hash::blake3_xof(b"hello", &mut expected);
+
+    assert_eq!(xof, expected);
+
+    let mut hasher = xck::hash::Blake3Hasher::new_keyed(&[7u8; 32]);
+
+    hasher.update(b"message");
+
+    assert_eq!(
+        hasher.finalize(),
+        xck::hash::blake3_mac(&[7u8; 32], b"message")
+    );
+
+    hasher.reset();
+
+    assert_eq!(hasher.finalize(), xck::hash::blake3_mac(&[7u8; 32], b""));
+
+    let mut hasher = xck::hash::Blake3Hasher::new_derive_key(xck::hash::BLAKE3_DEFAULT_CONTEXT);
+
+    hasher.update(b"key material");
+
+    assert_eq!(
+        hasher.finalize(),
+        xck::hash::blake3_kdf(xck::hash::BLAKE3_DEFAULT_CONTEXT, b"key material")
+    );
+}
+
+#[test]
+fn hasher_trait() {
+    use std::io::Write;
+
+    use xck::hash::Hasher;
+
+    let mut hashers: [(&mut dyn Hasher, &[u8]); 4] = [
+        (
+            &mut xck::hash::Sha256Hasher::new(),
+            &xck::hash::sha256(b"hello"),
+        ),
+        (
+            &mut xck::hash::Sha512Hasher::new(),
+            &xck::hash::sha512(b"hello"),
+        ),
+        (
+            &mut xck::hash::Sha512_256Hasher::new(),
+            &xck::hash::sha512_256(b"hello"),
+        ),
+        (
+            &mut xck::hash::Blake3Hasher::new(),
+            &xck::hash::blake3(b"hello"),
+        ),
+    ];
+
+    for (hasher, expected) in hashers.iter_mut() {
+        hasher.write_all(b"hello").unwrap();
+
+        let mut digest = [0u8; 64];
+
+        let len = hasher.finalize_into(&mut digest).unwrap();
+
+        assert_eq!(len, hasher.output_len());
+
+        assert_eq!(&digest[..len], *expected);
+
+        assert_eq!(
+            hasher.finalize_into(&mut [0u8; 16]).unwrap_err().kind(),
+            xck::ErrorKind::BufferTooSmall
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hash_reader() {
+    let message = vec![5u8; 100_000];
+
+    for algorithm in xck::hash::HashAlgorithm::ALL {
+        assert_eq!(
+            algorithm
+                .name()
+                .parse::<xck::hash::HashAlgorithm>()
+                .unwrap(),
+            algorithm
+        );
+
+        let digest = xck::hash::hash_reader(algorithm, &mut message.as_slice()).unwrap();
+
+        assert_eq!(digest.len(), algorithm.output_len());
+    }
+
+    assert_eq!(
+        xck::hash::hash_reader(xck::hash::HashAlgorithm::Sha512, &mut message.as_slice()).unwrap(),
+        xck::hash::sha512(&message)
+    );
+
+    assert!("md5".parse::<xck::hash::HashAlgorithm>().is_err());
+}
+
+// RFC 4231 test case 2
+#[test]
+fn hmac_sha256() {
+    const TEST_KEY: &[u8] = b"Jefe";
+
+    const TEST_MESSAGE: &[u8] = b"what do ya want for nothing?";
+
+    const TEST_TAG: [u8; 32] = [
+        91, 220, 193, 70, 191, 96, 117, 78, 106, 4, 36, 38, 8, 149, 117, 199, 90, 0, 63, 8, 157,
+        39, 57, 131, 157, 236, 88, 185, 100, 236, 56, 67,
+    ];
+
+    assert_eq!(xck::hash::hmac_sha256(TEST_KEY, TEST_MESSAGE), TEST_TAG);
+
+    assert!(xck::hash::hmac_sha256_verify(TEST_KEY, TEST_MESSAGE, &TEST_TAG).is_ok());
+
+    assert_eq!(
+        xck::hash::hmac_sha256_verify(TEST_KEY, b"what do ya want for something?", &TEST_TAG)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    assert!(xck::hash::hmac_sha256_verify(TEST_KEY, TEST_MESSAGE, &TEST_TAG[..16]).is_err());
+}
+
+// RFC 4231 test case 2
+#[test]
+fn hmac_sha512() {
+    const TEST_KEY: &[u8] = b"Jefe";
+
+    const TEST_MESSAGE: &[u8] = b"what do ya want for nothing?";
+
+    const TEST_TAG: [u8; 64] = [
+        22, 75, 122, 123, 252, 248, 25, 226, 227, 149, 251, 231, 59, 86, 224, 163, 135, 189, 100,
+        34, 46, 131, 31, 214, 16, 39, 12, 215, 234, 37, 5, 84, 151, 88, 191, 117, 192, 90, 153, 74,
+        109, 3, 79, 101, 248, 240, 230, 253, 202, 234, 177, 163, 77, 74, 107, 75, 99, 110, 7, 10,
+        56, 188, 231, 55,
+    ];
+
+    assert_eq!(xck::hash::hmac_sha512(TEST_KEY, TEST_MESSAGE), TEST_TAG);
+
+    assert!(xck::hash::hmac_sha512_verify(TEST_KEY, TEST_MESSAGE, &TEST_TAG).is_ok());
+
+    assert!(xck::hash::hmac_sha512_verify(b"jefe", TEST_MESSAGE, &TEST_TAG).is_err());
+}
+
+// RFC 5869 test case 1
+#[test]
+fn hkdf_sha256() {
+    const TEST_IKM: [u8; 22] = [11; 22];
+
+    const TEST_SALT: [u8; 13] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    const TEST_INFO: [u8; 10] = [240, 241, 242, 243, 244, 245, 246, 247, 248, 249];
+
+    const TEST_PRK: [u8; 32] = [
+        7, 119, 9, 54, 44, 46, 50, 223, 13, 220, 63, 13, 196, 123, 186, 99, 144, 182, 199, 59, 181,
+        15, 156, 49, 34, 236, 132, 74, 215, 194, 179, 229,
+    ];
+
+    const TEST_OKM: [u8; 42] = [
+        60, 178, 95, 37, 250, 172, 213, 122, 144, 67, 79, 100, 208, 54, 47, 42, 45, 45, 10, 144,
+        207, 26, 90, 76, 93, 176, 45, 86, 236, 196, 197, 191, 52, 0, 114, 8, 213, 184, 135, 24, 88,
+        101,
+    ];
+
+    assert_eq!(
+        xck::hash::hkdf_sha256_extract(&TEST_SALT, &TEST_IKM),
+        TEST_PRK
+    );
+
+    let mut okm = [0u8; 42];
+
+    xck::hash::hkdf_sha256_expand(&TEST_PRK, &TEST_INFO, &mut okm).unwrap();
+
+    assert_eq!(okm, TEST_OKM);
+
+    let mut okm = [0u8; 42];
+
+    xck::hash::hkdf_sha256(&TEST_SALT, &TEST_IKM, &TEST_INFO, &mut okm).unwrap();
+
+    assert_eq!(okm, TEST_OKM);
+
+    assert_eq!(
+        xck::hash::hkdf_sha256_expand(&TEST_PRK[..16], &TEST_INFO, &mut okm)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidKeyLength
+    );
+
+    assert_eq!(
+        xck::hash::hkdf_sha256_expand(&TEST_PRK, &TEST_INFO, &mut [0u8; 255 * 32 + 1])
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidLength
+    );
+}
+
+#[test]
+fn hkdf_sha512() {
+    const TEST_IKM: [u8; 22] = [11; 22];
+
+    const TEST_SALT: [u8; 13] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+    const TEST_INFO: [u8; 10] = [240, 241, 242, 243, 244, 245, 246, 247, 248, 249];
+
+    const TEST_OKM: [u8; 42] = [
+        131, 35, 144, 8, 108, 218, 113, 251, 71, 98, 91, 181, 206, 177, 104, 228, 200, 226, 106,
+        26, 22, 237, 52, 217, 252, 127, 233, 44, 20, 129, 87, 147, 56, 218, 54, 44, 184, 217, 249,
+        37, 215, 203,
+    ];
+
+    let mut okm = [0u8; 42];
+
+    xck::hash::hkdf_sha512(&TEST_SALT, &TEST_IKM, &TEST_INFO, &mut okm).unwrap();
+
+    assert_eq!(okm, TEST_OKM);
+
+    let prk = xck::hash::hkdf_sha512_extract(&TEST_SALT, &TEST_IKM);
+
+    let mut okm = [0u8; 42];
+
+    xck::hash::hkdf_sha512_expand(&prk, &TEST_INFO, &mut okm).unwrap();
+
+    assert_eq!(okm, TEST_OKM);
+}
+
+#[test]
+fn sha3_256() {
+    const TEST_DIGEST: [u8; 32] = [
+        51, 56, 190, 105, 79, 80, 197, 243, 56, 129, 73, 134, 205, 240, 104, 100, 83, 168, 136,
+        184, 79, 66, 77, 121, 42, 244, 185, 32, 35, 152, 243, 146,
+    ];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    assert_eq!(xck::hash::sha3_256(&TEST_MESSAGE), TEST_DIGEST);
+}
+
+#[test]
+fn sha3_512() {
+    const TEST_DIGEST: [u8; 64] = [
+        117, 213, 39, 195, 104, 242, 239, 232, 72, 236, 246, 176, 115, 163, 103, 103, 128, 8, 5,
+        233, 238, 242, 177, 133, 125, 95, 152, 79, 3, 110, 182, 223, 137, 29, 117, 247, 45, 155,
+        21, 69, 24, 193, 205, 88, 131, 82, 134, 209, 218, 154, 56, 222, 186, 61, 233, 139, 90, 83,
+        229, 237, 120, 168, 73, 118,
+    ];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    assert_eq!(xck::hash::sha3_512(&TEST_MESSAGE), TEST_DIGEST);
+}
+
+#[test]
+fn keccak256() {
+    const TEST_DIGEST: [u8; 32] = [
+        28, 138, 255, 149, 6, 133, 194, 237, 75, 195, 23, 79, 52, 114, 40, 123, 86, 217, 81, 123,
+        156, 148, 129, 39, 49, 154, 9, 167, 163, 109, 234, 200,
+    ];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    assert_eq!(xck::hash::keccak256(&TEST_MESSAGE), TEST_DIGEST);
+
+    assert_ne!(
+        xck::hash::keccak256(&TEST_MESSAGE),
+        xck::hash::sha3_256(&TEST_MESSAGE)
+    );
+}
+
+#[test]
+fn shake128() {
+    const TEST_OUTPUT: [u8; 40] = [
+        142, 180, 182, 169, 50, 242, 128, 51, 94, 225, 162, 121, 248, 194, 8, 163, 73, 231, 188,
+        101, 218, 248, 49, 211, 2, 28, 33, 56, 37, 41, 36, 99, 197, 158, 34, 208, 254, 44, 118,
+        124,
+    ];
+
+    let mut buffer = [0u8; 40];
+
+    xck::hash::shake128(b"hello", &mut buffer);
+
+    assert_eq!(buffer, TEST_OUTPUT);
+}
+
+#[test]
+fn shake256() {
+    const TEST_OUTPUT: [u8; 80] = [
+        18, 52, 7, 90, 228, 161, 231, 115, 22, 207, 45, 128, 0, 151, 69, 129, 163, 67, 185, 235,
+        188, 167, 227, 209, 219, 131, 57, 76, 48, 242, 33, 98, 111, 89, 78, 79, 13, 230, 57, 2, 52,
+        154, 94, 165, 120, 18, 19, 33, 88, 19, 145, 159, 146, 164, 216, 109, 18, 116, 102, 227,
+        208, 126, 139, 227, 140, 233, 244, 87, 191, 50, 230, 178, 108, 242, 121, 134, 184, 97, 221,
+        132,
+    ];
+
+    let mut buffer = [0u8; 80];
+
+    xck::hash::shake256(b"hello", &mut buffer);
+
+    assert_eq!(buffer, TEST_OUTPUT);
+}
+
+#[test]
+fn sha3_hasher() {
+    let mut sha3_256 = xck::hash::Sha3_256Hasher::new();
+
+    let mut sha3_512 = xck::hash::Sha3_512Hasher::new();
+
+    let mut keccak256 = xck::hash::Keccak256Hasher::new();
+
+    for chunk in [&b"hel"[..], b"lo"] {
+        sha3_256.update(chunk);
+
+        sha3_512.update(chunk);
+
+        keccak256.update(chunk);
+    }
+
+    assert_eq!(sha3_256.finalize(), xck::hash::sha3_256(b"hello"));
+
+    assert_eq!(sha3_512.finalize(), xck::hash::sha3_512(b"hello"));
+
+    assert_eq!(keccak256.finalize(), xck::hash::keccak256(b"hello"));
+}
+
+#[test]
+fn blake2b() {
+    const TEST_OUTPUT: [u8; 64] = [
+        186, 128, 165, 63, 152, 28, 77, 13, 106, 39, 151, 182, 159, 18, 246, 233, 76, 33, 47, 20,
+        104, 90, 196, 183, 75, 18, 187, 111, 219, 255, 162, 209, 125, 135, 197, 57, 42, 171, 121,
+        45, 194, 82, 213, 222, 69, 51, 204, 149, 24, 211, 138, 168, 219, 241, 146, 90, 185, 35,
+        134, 237, 212, 0, 153, 35,
+    ];
+
+    assert_eq!(xck::hash::blake2b(b"abc"), TEST_OUTPUT);
+}
+
+#[test]
+fn blake2s() {
+    const TEST_OUTPUT: [u8; 32] = [
+        80, 140, 94, 140, 50, 124, 20, 226, 225, 167, 43, 163, 78, 235, 69, 47, 55, 69, 139, 32,
+        158, 214, 58, 41, 77, 153, 155, 76, 134, 103, 89, 130,
+    ];
+
+    assert_eq!(xck::hash::blake2s(b"abc"), TEST_OUTPUT);
+}
+
+#[test]
+fn blake2_with_params() {
+    const TEST_BLAKE2B: [u8; 32] = [
+        32, 53, 59, 112, 13, 55, 117, 18, 39, 253, 124, 221, 69, 42, 7, 175, 25, 214, 40, 211, 7,
+        84, 124, 240, 8, 130, 113, 111, 239, 213, 176, 255,
+    ];
+
+    const TEST_BLAKE2S_MAC: [u8; 16] = [
+        199, 153, 150, 106, 241, 235, 79, 87, 37, 149, 176, 13, 191, 138, 40, 190,
+    ];
+
+    let params = xck::hash::Blake2Params {
+        key: b"key",
+        salt: b"salt",
+        personal: b"app",
+    };
+
+    let mut buffer = [0u8; 32];
+
+    xck::hash::blake2b_with_params(&params, b"hello", &mut buffer).unwrap();
+
+    assert_eq!(buffer, TEST_BLAKE2B);
+
+    let mut buffer = [0u8; 16];
+
+    xck::hash::blake2s_mac(b"key", b"hello", &mut buffer).unwrap();
+
+    assert_eq!(buffer, TEST_BLAKE2S_MAC);
+
+    let mut hasher = xck::hash::Blake2bHasher::with_params(&params, 32).unwrap();
+
+    assert_eq!(xck::hash::Hasher::output_len(&hasher), 32);
+
+    assert_eq!(
+        xck::hash::Hasher::output_len(&xck::hash::Blake2bHasher::new()),
+        64
+    );
+
+    assert_eq!(
+        xck::hash::Hasher::output_len(&xck::hash::Blake2sHasher::new()),
+        32
+    );
+
+    hasher.update(b"hel");
+
+    hasher.update(b"lo");
+
+    let mut buffer = [0u8; 32];
+
+    xck::hash::Hasher::finalize_into(&hasher, &mut buffer).unwrap();
+
+    assert_eq!(buffer, TEST_BLAKE2B);
+}
+
+#[test]
+fn blake2_invalid_params() {
+    let mut buffer = [0u8; 65];
+
+    assert_eq!(
+        xck::hash::blake2b_mac(b"key", b"hello", &mut buffer)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidLength
+    );
+
+    let mut buffer = [0u8; 32];
+
+    assert_eq!(
+        xck::hash::blake2s_mac(&[0u8; 33], b"hello", &mut buffer)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidKeyLength
+    );
+
+    let params = xck::hash::Blake2Params {
+        salt: &[0u8; 9],
+        ..Default::default()
+    };
+
+    assert!(xck::hash::blake2s_with_params(&params, b"hello", &mut buffer).is_err());
+}
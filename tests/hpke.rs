@@ -0,0 +1,354 @@
+// cargo test --features="alloc" --package xck --test hpke -- --nocapture
+
+// RFC 9180 A.1, DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, AES-128-GCM.
+// RFC 9180 A.2, DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, ChaCha20Poly1305.
+
+#[cfg(feature = "alloc")]
+fn hex(string: &str) -> Vec<u8> {
+    xck::format::hex_decode_alloc(string).unwrap()
+}
+
+// info is "Ode on a Grecian Urn"
+#[cfg(feature = "alloc")]
+const TEST_INFO: &str = "4f6465206f6e2061204772656369616e2055726e";
+
+// plain is "Beauty is truth, truth beauty"
+#[cfg(feature = "alloc")]
+const TEST_PLAIN: &[u8] = b"Beauty is truth, truth beauty";
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hpke_derive_keypair() {
+    // A.1.1 ikmE
+    let (private_key, public_key) = xck::hpke::derive_keypair(&hex(
+        "7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234",
+    ));
+
+    assert_eq!(
+        private_key.as_bytes().as_slice(),
+        hex("52c4a758a802cd8b936eceea314432798d5baf2d7e9235dc084ab1b9cfa2f736")
+    );
+
+    assert_eq!(
+        public_key.as_bytes().as_slice(),
+        hex("37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431")
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hpke_base() {
+    // A.1.1
+    let (private_key, _) = xck::hpke::derive_keypair(&hex(
+        "6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037",
+    ));
+
+    let enc = xck::types::X25519PublicKey::try_from(
+        hex("37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431").as_slice(),
+    )
+    .unwrap();
+
+    let mut receiver = xck::hpke::setup_base_r(
+        xck::hpke::Algorithm::Aes128Gcm,
+        &enc,
+        &private_key,
+        &hex(TEST_INFO),
+    )
+    .unwrap();
+
+    for (aad, cipher) in [
+        (
+            b"Count-0",
+            "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a",
+        ),
+        (
+            b"Count-1",
+            "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab251c03d0c22a56b8ca42c2063b84",
+        ),
+    ] {
+        assert_eq!(receiver.open(aad, &hex(cipher)).unwrap(), TEST_PLAIN);
+    }
+
+    assert_eq!(
+        receiver.export(b"", 32).unwrap(),
+        hex("3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee")
+    );
+
+    assert_eq!(
+        receiver.export(b"TestContext", 32).unwrap(),
+        hex("e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931")
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hpke_base_sender() {
+    // A.1.1 ikmE, ikmR
+    let (ephemeral_private_key, _) = xck::hpke::derive_keypair(&hex(
+        "7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234",
+    ));
+
+    let (_, public_key) = xck::hpke::derive_keypair(&hex(
+        "6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037",
+    ));
+
+    let (enc, mut sender) = xck::hpke::setup_base_s_with_ephemeral_key(
+        xck::hpke::Algorithm::Aes128Gcm,
+        &public_key,
+        &hex(TEST_INFO),
+        &ephemeral_private_key,
+    )
+    .unwrap();
+
+    assert_eq!(
+        enc.as_bytes().as_slice(),
+        hex("37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431")
+    );
+
+    for (aad, cipher) in [
+        (
+            b"Count-0",
+            "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a",
+        ),
+        (
+            b"Count-1",
+            "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab251c03d0c22a56b8ca42c2063b84",
+        ),
+    ] {
+        assert_eq!(sender.seal(aad, TEST_PLAIN).unwrap(), hex(cipher));
+    }
+
+    assert_eq!(
+        sender.export(b"", 32).unwrap(),
+        hex("3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee")
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hpke_base_chacha20_poly1305() {
+    // A.2.1 ikmE, ikmR
+    let (ephemeral_private_key, ephemeral_public_key) = xck::hpke::derive_keypair(&hex(
+        "909a9b35d3dc4713a5e72a4da274b55d3d3821a37e5d099e74a647db583a904b",
+    ));
+
+    let (private_key, public_key) = xck::hpke::derive_keypair(&hex(
+        "1ac01f181fdf9f352797655161c58b75c656a6cc2716dcb66372da835542e1df",
+    ));
+
+    assert_eq!(
+        ephemeral_private_key.as_bytes().as_slice(),
+        hex("f4ec9b33b792c372c1d2c2063507b684ef925b8c75a42dbcbf57d63ccd381600")
+    );
+
+    assert_eq!(
+        private_key.as_bytes().as_slice(),
+        hex("8057991eef8f1f1af18f4a9491d16a1ce333f695d4db8e38da75975c4478e0fb")
+    );
+
+    let (enc, mut sender) = xck::hpke::setup_base_s_with_ephemeral_key(
+        xck::hpke::Algorithm::ChaCha20Poly1305,
+        &public_key,
+        &hex(TEST_INFO),
+        &ephemeral_private_key,
+    )
+    .unwrap();
+
+    assert_eq!(enc, ephemeral_public_key);
+
+    assert_eq!(
+        enc.as_bytes().as_slice(),
+        hex("1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a")
+    );
+
+    let cipher = hex(
+        "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db21993c62ce81883d2dd1b51a28",
+    );
+
+    assert_eq!(sender.seal(b"Count-0", TEST_PLAIN).unwrap(), cipher);
+
+    let mut receiver = xck::hpke::setup_base_r(
+        xck::hpke::Algorithm::ChaCha20Poly1305,
+        &enc,
+        &private_key,
+        &hex(TEST_INFO),
+    )
+    .unwrap();
+
+    assert_eq!(receiver.open(b"Count-0", &cipher).unwrap(), TEST_PLAIN);
+
+    for (exporter_context, exported) in [
+        (
+            "",
+            "4bbd6243b8bb54cec311fac9df81841b6fd61f56538a775e7c80a9f40160606e",
+        ),
+        (
+            "00",
+            "8c1df14732580e5501b00f82b10a1647b40713191b7c1240ac80e2b68808ba69",
+        ),
+        (
+            "54657374436f6e74657874",
+            "5acb09211139c43b3090489a9da433e8a30ee7188ba8b0a9a1ccf0c229283e53",
+        ),
+    ] {
+        assert_eq!(
+            sender.export(&hex(exporter_context), 32).unwrap(),
+            hex(exported)
+        );
+
+        assert_eq!(
+            receiver.export(&hex(exporter_context), 32).unwrap(),
+            hex(exported)
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hpke_auth() {
+    // A.1.3
+    let private_key = xck::types::X25519SecretKey::try_from(
+        hex("fdea67cf831f1ca98d8e27b1f6abeb5b7745e9d35348b80fa407ff6958f9137e").as_slice(),
+    )
+    .unwrap();
+
+    let sender_public_key = xck::types::X25519PublicKey::try_from(
+        hex("8b0c70873dc5aecb7f9ee4e62406a397b350e57012be45cf53b7105ae731790b").as_slice(),
+    )
+    .unwrap();
+
+    let enc = xck::types::X25519PublicKey::try_from(
+        hex("23fb952571a14a25e3d678140cd0e5eb47a0961bb18afcf85896e5453c312e76").as_slice(),
+    )
+    .unwrap();
+
+    let cipher = hex(
+        "5fd92cc9d46dbf8943e72a07e42f363ed5f721212cd90bcfd072bfd9f44e06b80fd17824947496e21b680c141b",
+    );
+
+    let mut receiver = xck::hpke::setup_auth_r(
+        xck::hpke::Algorithm::Aes128Gcm,
+        &enc,
+        &private_key,
+        &hex(TEST_INFO),
+        &sender_public_key,
+    )
+    .unwrap();
+
+    assert_eq!(receiver.open(b"Count-0", &cipher).unwrap(), TEST_PLAIN);
+
+    assert_eq!(
+        receiver.export(b"", 32).unwrap(),
+        hex("28c70088017d70c896a8420f04702c5a321d9cbf0279fba899b59e51bac72c85")
+    );
+
+    // A.1.3 ikmE, ikmS
+    let (ephemeral_private_key, _) = xck::hpke::derive_keypair(&hex(
+        "6e6d8f200ea2fb20c30b003a8b4f433d2f4ed4c2658d5bc8ce2fef718059c9f7",
+    ));
+
+    let (sender_private_key, _) = xck::hpke::derive_keypair(&hex(
+        "94b020ce91d73fca4649006c7e7329a67b40c55e9e93cc907d282bbbff386f58",
+    ));
+
+    let (sender_enc, mut sender) = xck::hpke::setup_auth_s_with_ephemeral_key(
+        xck::hpke::Algorithm::Aes128Gcm,
+        &xck::asymmetric::x25519_gen_public_key(&private_key),
+        &hex(TEST_INFO),
+        &sender_private_key,
+        &ephemeral_private_key,
+    )
+    .unwrap();
+
+    assert_eq!(sender_enc, enc);
+
+    assert_eq!(sender.seal(b"Count-0", TEST_PLAIN).unwrap(), cipher);
+
+    // A different sender does not authenticate.
+    assert!(xck::hpke::open_auth(
+        xck::hpke::Algorithm::Aes128Gcm,
+        &enc,
+        &private_key,
+        &hex(TEST_INFO),
+        b"Count-0",
+        &cipher,
+        &xck::asymmetric::x25519_gen_keypair().1,
+    )
+    .is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hpke_round_trip() {
+    let (private_key, public_key) = xck::asymmetric::x25519_gen_keypair();
+
+    let (sender_private_key, sender_public_key) = xck::asymmetric::x25519_gen_keypair();
+
+    for algorithm in [
+        xck::hpke::Algorithm::Aes128Gcm,
+        xck::hpke::Algorithm::Aes256Gcm,
+        xck::hpke::Algorithm::ChaCha20Poly1305,
+    ] {
+        let (enc, mut sender) = xck::hpke::setup_base_s(algorithm, &public_key, b"info").unwrap();
+
+        let mut receiver = xck::hpke::setup_base_r(algorithm, &enc, &private_key, b"info").unwrap();
+
+        for plain in [&b"hello"[..], b"", b"world"] {
+            let cipher = sender.seal(b"aad", plain).unwrap();
+
+            assert_eq!(receiver.open(b"aad", &cipher).unwrap(), plain);
+        }
+
+        assert_eq!(
+            sender.export(b"context", 64).unwrap(),
+            receiver.export(b"context", 64).unwrap()
+        );
+
+        let (enc, cipher) = xck::hpke::seal_auth(
+            algorithm,
+            &public_key,
+            b"info",
+            &[],
+            b"hello",
+            &sender_private_key,
+        )
+        .unwrap();
+
+        assert_eq!(
+            xck::hpke::open_auth(
+                algorithm,
+                &enc,
+                &private_key,
+                b"info",
+                &[],
+                &cipher,
+                &sender_public_key
+            )
+            .unwrap(),
+            b"hello"
+        );
+
+        assert!(
+            xck::hpke::open_base(algorithm, &enc, &private_key, b"info", &[], &cipher).is_err()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn hpke_unsupported() {
+    let (_, public_key) = xck::asymmetric::x25519_gen_keypair();
+
+    assert_eq!(
+        xck::hpke::seal_base(
+            xck::hpke::Algorithm::XChaCha20Poly1305,
+            &public_key,
+            &[],
+            &[],
+            b"hello"
+        )
+        .unwrap_err()
+        .kind(),
+        xck::ErrorKind::Unsupported
+    );
+}
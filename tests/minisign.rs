@@ -0,0 +1,252 @@
+// cargo test --features="alloc" --package xck --test minisign -- --nocapture
+
+// Made by minisign. (minisign-verify test vectors)
+#[cfg(feature = "alloc")]
+const TEST_MINISIGN_PUBLIC_KEY: &str = "untrusted comment: minisign public key E7620F1842B4E81F
+RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3
+";
+
+#[cfg(feature = "alloc")]
+const TEST_MINISIGN_SIGNATURE_LEGACY: &str = "untrusted comment: signature from minisign secret key
+RWQf6LRCGA9i59SLOFxz6NxvASXDJeRtuZykwQepbDEGt87ig1BNpWaVWuNrm73YiIiJbq71Wi+dP9eKL8OC351vwIasSSbXxwA=
+trusted comment: timestamp:1555779966\tfile:test
+QtKMXWyYcwdpZAlPF7tE2ENJkRd1ujvKjlj1m9RtHTBnZPa5WKU5uWRs5GoP5M/VqE81QFuMKI5k/SfNQUaOAA==
+";
+
+#[cfg(feature = "alloc")]
+const TEST_MINISIGN_SIGNATURE_PREHASHED: &str =
+    "untrusted comment: signature from minisign secret key
+RUQf6LRCGA9i559r3g7V1qNyJDApGip8MfqcadIgT9CuhV3EMhHoN1mGTkUidF/z7SrlQgXdy8ofjb7bNJJylDOocrCo8KLzZwo=
+trusted comment: timestamp:1556193335\tfile:test
+y/rUw2y8/hOUYjZU71eHp/Wo1KZ40fGy2VJEDl34XMJM+TX48Ss/17u3IvIfbVR1FkZZSNCisQbuQY+bHwhEBg==
+";
+
+// Made by libsodium: seed 0..31, password "secret", opslimit 131072 and memlimit 1048576 (N = 2^10, r = 8, p = 4).
+#[cfg(feature = "alloc")]
+const TEST_SECRET_KEY_ENCRYPTED: &str = "untrusted comment: minisign encrypted secret key
+RWRTY0IyZGVmZ2hpamtsbW5vcHFyc3R1dnd4eXp7fH1+f4CBgoMAAAIAAAAAAAAAEAAAAAAAKSd+ziO7P9CkwdFX/n4NqeiBgEXymR5tJLzIziiLc6lz7juoCB/SxJXb4OYV3ONeW6Zz7llKwFooD1fptNv4aQTUC0QsUxvpkhY7OIt6BRk32icA9EDPWTpV/gT2k9IPuIg18Xiy8Q0=
+";
+
+#[cfg(feature = "alloc")]
+const TEST_SECRET_KEY: &str = "untrusted comment: minisign secret key
+RWQAAEIyAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHy49TFtqeYgAAQIDBAUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0eHwOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4bS2+HsgERzG01gBnVgSE+mguw4hKip3BRB5+v7d08ag=
+";
+
+#[cfg(feature = "alloc")]
+const TEST_PUBLIC_KEY: &str = "untrusted comment: minisign public key 88796A5B4C3D2E1F
+RWQfLj1MW2p5iAOhB7/zzhC+HXDdGOdLwJln5NYwm6UNXx3chmQSVTG4
+";
+
+#[cfg(feature = "alloc")]
+const TEST_SIGNATURE: &str = "untrusted comment: signature from minisign secret key
+RUQfLj1MW2p5iBMyWdOFTFZv9QWYN35BjApAX1XuhYjkGw2/W/LzbkOmjByqQCgzAkBBazHdrhIYttE2uBB87idsPQkB6MVdqw0=
+trusted comment: timestamp:1700000000\tfile:hello.txt\thashed
+EZ5YjVx137XOjmaZDiQwNAVr12U3qDs5KNQ8sRAK0nuGg7fMKLc87rysORrah2d+VcBjZFmQgoTqqCJyDoipDA==
+";
+
+#[cfg(feature = "alloc")]
+const TEST_SIGNATURE_LEGACY: &str = "untrusted comment: signature from minisign secret key
+RWQfLj1MW2p5iCg+bw2c/1YcCucowz0aFVRVgHxToB5gUfCj/Jp1WrpQ8NfEC4d7JUhxEHtqwzx+TrSdy6ng41WhOoOdP/Y9XgU=
+trusted comment: timestamp:1700000000\tfile:hello.txt
+j0Sqv+WS6dBmZFJezUC5GH81BP/AO2vqx+f6ok60TKBkMGPFDPDOCpft4XyHT4H31Q1aoTEBLg4nBxsMXP59CA==
+";
+
+#[test]
+#[cfg(feature = "alloc")]
+fn minisign_verify() {
+    let public_key = xck::minisign::decode_public_key(TEST_MINISIGN_PUBLIC_KEY).unwrap();
+
+    assert_eq!(
+        xck::minisign::decode_public_key(
+            "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3"
+        )
+        .unwrap(),
+        public_key
+    );
+
+    assert_eq!(
+        xck::minisign::encode_public_key(&public_key).unwrap(),
+        TEST_MINISIGN_PUBLIC_KEY
+    );
+
+    assert_eq!(
+        xck::minisign::verify(
+            &public_key,
+            TEST_MINISIGN_SIGNATURE_LEGACY,
+            &mut &b"test"[..]
+        )
+        .unwrap(),
+        "timestamp:1555779966\tfile:test"
+    );
+
+    assert_eq!(
+        xck::minisign::verify(
+            &public_key,
+            TEST_MINISIGN_SIGNATURE_PREHASHED,
+            &mut &b"test"[..]
+        )
+        .unwrap(),
+        "timestamp:1556193335\tfile:test"
+    );
+
+    assert_eq!(
+        xck::minisign::verify(
+            &public_key,
+            TEST_MINISIGN_SIGNATURE_PREHASHED,
+            &mut &b"Test"[..]
+        )
+        .unwrap_err()
+        .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    // The trusted comment is signed.
+    let tampered = TEST_MINISIGN_SIGNATURE_PREHASHED.replace("file:test", "file:tesT");
+
+    assert_eq!(
+        xck::minisign::verify(&public_key, &tampered, &mut &b"test"[..])
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    // Another key.
+    assert_eq!(
+        xck::minisign::verify(
+            &xck::minisign::decode_public_key(TEST_PUBLIC_KEY).unwrap(),
+            TEST_MINISIGN_SIGNATURE_PREHASHED,
+            &mut &b"test"[..]
+        )
+        .unwrap_err()
+        .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn minisign_secret_key() {
+    let secret_key =
+        xck::minisign::decode_secret_key(TEST_SECRET_KEY_ENCRYPTED, Some(b"secret")).unwrap();
+
+    let public_key = xck::minisign::decode_public_key(TEST_PUBLIC_KEY).unwrap();
+
+    assert_eq!(secret_key.public_key(), public_key);
+
+    assert_eq!(
+        xck::minisign::decode_secret_key(TEST_SECRET_KEY, None)
+            .unwrap()
+            .private_key
+            .as_bytes(),
+        secret_key.private_key.as_bytes()
+    );
+
+    assert_eq!(
+        xck::minisign::decode_secret_key(TEST_SECRET_KEY_ENCRYPTED, Some(b"wrong"))
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    assert_eq!(
+        xck::minisign::decode_secret_key(TEST_SECRET_KEY_ENCRYPTED, None)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidParameter
+    );
+
+    assert_eq!(
+        xck::minisign::encode_secret_key(&secret_key, None).unwrap(),
+        TEST_SECRET_KEY
+    );
+
+    let encoded = xck::minisign::encode_secret_key_with_limits(
+        &secret_key,
+        Some(b"password"),
+        32768,
+        16777216,
+    )
+    .unwrap();
+
+    assert_eq!(
+        xck::minisign::decode_secret_key(&encoded, Some(b"password"))
+            .unwrap()
+            .public_key(),
+        public_key
+    );
+
+    // A large opslimit with a small memlimit would make p huge.
+    assert_eq!(
+        xck::minisign::encode_secret_key_with_limits(
+            &secret_key,
+            Some(b"password"),
+            xck::minisign::MAX_OPSLIMIT + 1,
+            16777216,
+        )
+        .unwrap_err()
+        .kind(),
+        xck::ErrorKind::InvalidParameter
+    );
+
+    // sig_alg || kdf_alg || chk_alg || salt || opslimit || memlimit || keynum_sk
+    let mut lines = encoded.lines();
+
+    let comment = lines.next().unwrap();
+
+    let mut bytes = xck::format::base64_decode_alloc(lines.next().unwrap()).unwrap();
+
+    bytes[38..46].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    let tampered = format!("{comment}\n{}\n", xck::format::base64_encode_alloc(&bytes));
+
+    assert_eq!(
+        xck::minisign::decode_secret_key(&tampered, Some(b"password"))
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidParameter
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn minisign_sign() {
+    let secret_key = xck::minisign::decode_secret_key(TEST_SECRET_KEY, None).unwrap();
+
+    let public_key = secret_key.public_key();
+
+    let message = b"hello world\n";
+
+    // Ed25519 is deterministic.
+    assert_eq!(
+        xck::minisign::sign(
+            &secret_key,
+            &mut &message[..],
+            xck::minisign::DEFAULT_UNTRUSTED_COMMENT,
+            "timestamp:1700000000\tfile:hello.txt\thashed"
+        )
+        .unwrap(),
+        TEST_SIGNATURE
+    );
+
+    assert_eq!(
+        xck::minisign::sign_legacy(
+            &secret_key,
+            message,
+            xck::minisign::DEFAULT_UNTRUSTED_COMMENT,
+            "timestamp:1700000000\tfile:hello.txt"
+        )
+        .unwrap(),
+        TEST_SIGNATURE_LEGACY
+    );
+
+    for signature in [TEST_SIGNATURE, TEST_SIGNATURE_LEGACY] {
+        assert!(xck::minisign::verify(&public_key, signature, &mut &message[..]).is_ok());
+    }
+
+    assert_eq!(
+        xck::minisign::sign(&secret_key, &mut &message[..], "", "two\nlines")
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidParameter
+    );
+}
@@ -0,0 +1,169 @@
+// cargo test --features="alloc" --package xck --test signify -- --nocapture
+
+// Made by libsodium in the signify format: seed 32..63, no passphrase (signify -n).
+#[cfg(feature = "alloc")]
+const TEST_SECRET_KEY: &str = "untrusted comment: signify secret key
+RWRCSwAAAAAAAQIDBAUGBwgJCgsMDQ4Pm2zFQvRyvU+hssPU5fYHGCAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4/Kay64UG8yvCyLhqU000LxzYeUm0L/hLIl5S8kyKWbdc=
+";
+
+#[cfg(feature = "alloc")]
+const TEST_PUBLIC_KEY: &str = "untrusted comment: signify public key
+RWShssPU5fYHGCmsuuFBvMrwsi4alNNNC8c2HlJtC/4SyJeUvJMilm3X
+";
+
+#[cfg(feature = "alloc")]
+const TEST_SIGNATURE: &str = "untrusted comment: verify with key.pub
+RWShssPU5fYHGGcxp8bxnkbnmhqLK2vRim/hzvWx6SrIQmCeRGbgGLE1mK+bI608I4Zs/ERPp46rfrDAh/0L1XtBJ18a16eHPgw=
+";
+
+#[test]
+#[cfg(feature = "alloc")]
+fn signify() {
+    let secret_key = xck::signify::decode_secret_key(TEST_SECRET_KEY, None).unwrap();
+
+    let public_key = xck::signify::decode_public_key(TEST_PUBLIC_KEY).unwrap();
+
+    assert_eq!(secret_key.public_key(), public_key);
+
+    assert_eq!(
+        xck::signify::encode_public_key(&public_key, "signify").unwrap(),
+        TEST_PUBLIC_KEY
+    );
+
+    let message = b"hello world\n";
+
+    assert_eq!(
+        xck::signify::sign(&secret_key, message, "verify with key.pub").unwrap(),
+        TEST_SIGNATURE
+    );
+
+    assert!(xck::signify::verify(&public_key, TEST_SIGNATURE, message).is_ok());
+
+    assert_eq!(
+        xck::signify::verify(&public_key, TEST_SIGNATURE, b"hello world")
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    let (_, other_public_key) = xck::signify::gen_keypair();
+
+    assert_eq!(
+        xck::signify::verify(&other_public_key, TEST_SIGNATURE, message)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn signify_secret_key() {
+    let (secret_key, public_key) = xck::signify::gen_keypair();
+
+    let encoded = xck::signify::encode_secret_key(&secret_key, "signify", Some(b"secret")).unwrap();
+
+    assert!(encoded.starts_with("untrusted comment: signify secret key\n"));
+
+    assert_eq!(
+        xck::signify::decode_secret_key(&encoded, Some(b"secret"))
+            .unwrap()
+            .public_key(),
+        public_key
+    );
+
+    assert_eq!(
+        xck::signify::decode_secret_key(&encoded, Some(b"wrong"))
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    assert_eq!(
+        xck::signify::decode_secret_key(&encoded, None)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidParameter
+    );
+
+    let encoded = xck::signify::encode_secret_key(&secret_key, "signify", None).unwrap();
+
+    assert_eq!(
+        xck::signify::decode_secret_key(&encoded, None)
+            .unwrap()
+            .public_key(),
+        public_key
+    );
+
+    assert!(
+        xck::signify::decode_public_key("untrusted comment: signify public key\nRWQ=").is_err()
+    );
+}
+
+// signify -G (passphrase "passphrase", 42 bcrypt_pbkdf rounds) and signify -S of "hello world\n".
+// Made outside xck, following signify.c with the OpenBSD bcrypt_pbkdf test vectors.
+#[cfg(feature = "alloc")]
+const TEST_SECRET_KEY_ENCRYPTED: &str = "untrusted comment: signify secret key
+RWRCSwAAACp0/VpVGXUnQFj4g77nevJBJsFB5E3/OqdNNLSTHddmB2CYf/ynV2G+Nr4pbR5E0+Xf9xn442OFS5BWn+pzVaEZxePf9T7n8GoxheRPOLgN15Lpj2U+zUxKPl3u3UccCkQ=
+";
+
+#[cfg(feature = "alloc")]
+const TEST_PUBLIC_KEY_ENCRYPTED: &str = "untrusted comment: signify public key
+RWRNNLSTHddmB8FiQs826u77tn1Ncy7QaWvi2nu/znf3aZtktPf34pfH
+";
+
+#[cfg(feature = "alloc")]
+const TEST_SIGNATURE_ENCRYPTED: &str = "untrusted comment: verify with key.pub
+RWRNNLSTHddmBzTOQ0Nm7D5GMY3ocpzGZtpCkQickHoKd02S3wPMUeAvpTZU8VvDtatyqkwa4GdYSjkMAOykLhPH4PVtAP+QQQY=
+";
+
+#[test]
+#[cfg(feature = "alloc")]
+fn signify_passphrase() {
+    let secret_key =
+        xck::signify::decode_secret_key(TEST_SECRET_KEY_ENCRYPTED, Some(b"passphrase")).unwrap();
+
+    let public_key = xck::signify::decode_public_key(TEST_PUBLIC_KEY_ENCRYPTED).unwrap();
+
+    assert_eq!(secret_key.public_key(), public_key);
+
+    assert_eq!(
+        xck::signify::decode_secret_key(TEST_SECRET_KEY_ENCRYPTED, Some(b"wrong"))
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::AuthenticationFailed
+    );
+
+    let message = b"hello world\n";
+
+    assert!(xck::signify::verify(&public_key, TEST_SIGNATURE_ENCRYPTED, message).is_ok());
+
+    assert_eq!(
+        xck::signify::sign(&secret_key, message, "verify with key.pub").unwrap(),
+        TEST_SIGNATURE_ENCRYPTED
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn signify_rounds_too_high() {
+    // pkalg || kdfalg || kdfrounds || salt || checksum || keynum || seckey
+    let mut lines = TEST_SECRET_KEY_ENCRYPTED.lines();
+
+    let comment = lines.next().unwrap();
+
+    let mut bytes = xck::format::base64_decode_alloc(lines.next().unwrap()).unwrap();
+
+    for rounds in [xck::signify::MAX_ROUNDS + 1, u32::MAX] {
+        bytes[4..8].copy_from_slice(&rounds.to_be_bytes());
+
+        let tampered = format!("{comment}\n{}\n", xck::format::base64_encode_alloc(&bytes));
+
+        assert_eq!(
+            xck::signify::decode_secret_key(&tampered, Some(b"passphrase"))
+                .unwrap_err()
+                .kind(),
+            xck::ErrorKind::InvalidParameter
+        );
+    }
+}
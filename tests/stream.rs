@@ -0,0 +1,123 @@
+// cargo test --features="alloc" --package xck --test stream -- --nocapture
+
+#[cfg(feature = "alloc")]
+fn stream_encrypt(algorithm: xck::stream::Algorithm, key: &[u8], plain: &[u8]) -> Vec<u8> {
+    let mut cipher = Vec::new();
+
+    xck::stream::encrypt(algorithm, key, &mut &plain[..], &mut cipher).unwrap();
+
+    cipher
+}
+
+#[cfg(feature = "alloc")]
+fn stream_decrypt(
+    algorithm: xck::stream::Algorithm,
+    key: &[u8],
+    cipher: &[u8],
+) -> xck::Result<Vec<u8>> {
+    let mut plain = Vec::new();
+
+    xck::stream::decrypt(algorithm, key, &mut &cipher[..], &mut plain)?;
+
+    Ok(plain)
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn stream_round_trip() {
+    const ALGORITHMS: [xck::stream::Algorithm; 5] = [
+        xck::stream::Algorithm::Aes128Gcm,
+        xck::stream::Algorithm::Aes192Gcm,
+        xck::stream::Algorithm::Aes256Gcm,
+        xck::stream::Algorithm::ChaCha20Poly1305,
+        xck::stream::Algorithm::XChaCha20Poly1305,
+    ];
+
+    let key = xck::rand::gen_32();
+
+    for algorithm in ALGORITHMS {
+        let key = &key[..algorithm.key_len()];
+
+        for len in [
+            0,
+            5,
+            xck::stream::SEGMENT_SIZE,
+            xck::stream::SEGMENT_SIZE + 1,
+            xck::stream::SEGMENT_SIZE * 3,
+        ] {
+            let plain = vec![7u8; len];
+
+            let cipher = stream_encrypt(algorithm, key, &plain);
+
+            assert_eq!(stream_decrypt(algorithm, key, &cipher).unwrap(), plain);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn stream_detect_truncation() {
+    let algorithm = xck::stream::Algorithm::XChaCha20Poly1305;
+
+    let key = xck::rand::gen_32();
+
+    let plain = vec![7u8; xck::stream::SEGMENT_SIZE * 2 + 10];
+
+    let cipher = stream_encrypt(algorithm, &key, &plain);
+
+    // Drop the last segment, leaving a stream that ends on a segment boundary.
+    let len = xck::stream::nonce_prefix_len(algorithm)
+        + (xck::stream::SEGMENT_SIZE + xck::stream::TAG_SIZE) * 2;
+
+    assert!(stream_decrypt(algorithm, &key, &cipher[..len]).is_err());
+
+    assert!(stream_decrypt(algorithm, &key, &cipher[..cipher.len() - 1]).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn stream_detect_reorder() {
+    let algorithm = xck::stream::Algorithm::ChaCha20Poly1305;
+
+    let key = xck::rand::gen_32();
+
+    let mut plain = vec![1u8; xck::stream::SEGMENT_SIZE];
+
+    plain.extend(vec![2u8; xck::stream::SEGMENT_SIZE]);
+
+    plain.extend(vec![3u8; 10]);
+
+    let cipher = stream_encrypt(algorithm, &key, &plain);
+
+    let prefix_len = xck::stream::nonce_prefix_len(algorithm);
+
+    let segment_len = xck::stream::SEGMENT_SIZE + xck::stream::TAG_SIZE;
+
+    let mut reordered = cipher[..prefix_len].to_vec();
+
+    reordered.extend_from_slice(&cipher[prefix_len + segment_len..prefix_len + segment_len * 2]);
+
+    reordered.extend_from_slice(&cipher[prefix_len..prefix_len + segment_len]);
+
+    reordered.extend_from_slice(&cipher[prefix_len + segment_len * 2..]);
+
+    assert!(stream_decrypt(algorithm, &key, &reordered).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn stream_detect_tamper() {
+    let algorithm = xck::stream::Algorithm::Aes256Gcm;
+
+    let key = xck::rand::gen_32();
+
+    let mut cipher = stream_encrypt(algorithm, &key, b"hello");
+
+    let len = cipher.len();
+
+    cipher[len - 1] ^= 1;
+
+    assert!(stream_decrypt(algorithm, &key, &cipher).is_err());
+
+    assert!(stream_decrypt(algorithm, &xck::rand::gen_32(), &cipher[..len - 1]).is_err());
+}
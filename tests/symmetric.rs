@@ -17,7 +17,13 @@ fn aes_256_gcm_encrypt_alloc() {
     ];
 
     assert_eq!(
-        xck::symmetric::aes_256_gcm_encrypt_alloc(&TEST_KEY, &TEST_NONCE, &[], &TEST_MESSAGE).unwrap(),
+        xck::symmetric::aes_256_gcm_encrypt_alloc(
+            &xck::types::Aes256GcmKey::from(TEST_KEY),
+            &xck::types::Nonce12::from(TEST_NONCE),
+            &[],
+            &TEST_MESSAGE
+        )
+        .unwrap(),
         TEST_CIPHER
     );
 }
@@ -41,7 +47,13 @@ fn aes_256_gcm_decrypt_alloc() {
     ];
 
     assert_eq!(
-        xck::symmetric::aes_256_gcm_decrypt_alloc(&TEST_KEY, &TEST_NONCE, &[], &TEST_CIPHER).unwrap(),
+        xck::symmetric::aes_256_gcm_decrypt_alloc(
+            &xck::types::Aes256GcmKey::from(TEST_KEY),
+            &xck::types::Nonce12::from(TEST_NONCE),
+            &[],
+            &TEST_CIPHER
+        )
+        .unwrap(),
         TEST_MESSAGE
     );
 }
@@ -67,8 +79,13 @@ fn xchacha20_poly1305_decrypt() {
     ];
 
     assert_eq!(
-        xck::symmetric::xchacha20_poly1305_decrypt_alloc(&TEST_KEY, &TEST_NONCE, &[], &TEST_CIPHER)
-            .unwrap(),
+        xck::symmetric::xchacha20_poly1305_decrypt_alloc(
+            &xck::types::XChaCha20Poly1305Key::from(TEST_KEY),
+            &xck::types::Nonce24::from(TEST_NONCE),
+            &[],
+            &TEST_CIPHER
+        )
+        .unwrap(),
         TEST_MESSAGE
     );
 }
@@ -94,8 +111,13 @@ fn xchacha20_poly1305_encrypt_alloc() {
     ];
 
     assert_eq!(
-        xck::symmetric::xchacha20_poly1305_encrypt_alloc(&TEST_KEY, &TEST_NONCE, &[], &TEST_MESSAGE)
-            .unwrap(),
+        xck::symmetric::xchacha20_poly1305_encrypt_alloc(
+            &xck::types::XChaCha20Poly1305Key::from(TEST_KEY),
+            &xck::types::Nonce24::from(TEST_NONCE),
+            &[],
+            &TEST_MESSAGE
+        )
+        .unwrap(),
         TEST_CIPHER
     );
 }
@@ -119,13 +141,17 @@ fn chacha20_poly1305_decrypt_alloc() {
     ];
 
     assert_eq!(
-        xck::symmetric::chacha20_poly1305_decrypt_alloc(&TEST_KEY, &TEST_NONCE, &[], &TEST_CIPHER)
-            .unwrap(),
+        xck::symmetric::chacha20_poly1305_decrypt_alloc(
+            &xck::types::ChaCha20Poly1305Key::from(TEST_KEY),
+            &xck::types::Nonce12::from(TEST_NONCE),
+            &[],
+            &TEST_CIPHER
+        )
+        .unwrap(),
         TEST_MESSAGE
     );
 }
 
-
 #[test]
 #[cfg(feature = "alloc")]
 fn chacha20_poly1305_encrypt_alloc() {
@@ -145,8 +171,117 @@ fn chacha20_poly1305_encrypt_alloc() {
     ];
 
     assert_eq!(
-        xck::symmetric::chacha20_poly1305_encrypt_alloc(&TEST_KEY, &TEST_NONCE, &[], &TEST_MESSAGE)
+        xck::symmetric::chacha20_poly1305_encrypt_alloc(
+            &xck::types::ChaCha20Poly1305Key::from(TEST_KEY),
+            &xck::types::Nonce12::from(TEST_NONCE),
+            &[],
+            &TEST_MESSAGE
+        )
+        .unwrap(),
+        TEST_CIPHER
+    );
+}
+
+#[test]
+fn aead_algorithm() {
+    for algorithm in xck::symmetric::AeadAlgorithm::ALL {
+        assert_eq!(
+            algorithm
+                .name()
+                .parse::<xck::symmetric::AeadAlgorithm>()
+                .unwrap(),
+            algorithm
+        );
+
+        assert_eq!(algorithm.tag_len(), 16);
+    }
+
+    assert_eq!(
+        "AES-128-GCM"
+            .parse::<xck::symmetric::AeadAlgorithm>()
+            .unwrap()
+            .key_len(),
+        16
+    );
+
+    assert_eq!(xck::symmetric::AeadAlgorithm::Aes192Gcm.key_len(), 24);
+
+    assert_eq!(
+        xck::symmetric::AeadAlgorithm::XChaCha20Poly1305.nonce_len(),
+        24
+    );
+
+    assert_eq!(
+        "aes-512-gcm"
+            .parse::<xck::symmetric::AeadAlgorithm>()
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::Unsupported
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn aead_encrypt_alloc() {
+    const TEST_KEY: [u8; 32] = [
+        57, 175, 86, 245, 102, 95, 243, 137, 254, 235, 187, 7, 87, 88, 175, 190, 102, 82, 188, 163,
+        54, 51, 85, 130, 172, 177, 0, 252, 130, 32, 174, 81,
+    ];
+
+    const TEST_NONCE: [u8; 12] = [237, 234, 221, 165, 161, 138, 43, 236, 203, 229, 63, 230];
+
+    // hello = [104, 101, 108, 108, 111]
+    const TEST_MESSAGE: [u8; 5] = [104, 101, 108, 108, 111];
+
+    const TEST_CIPHER: [u8; 21] = [
+        137, 100, 3, 0, 89, 137, 198, 236, 253, 242, 215, 211, 190, 34, 227, 115, 73, 197, 139,
+        194, 158,
+    ];
+
+    let algorithm = xck::symmetric::AeadAlgorithm::Aes256Gcm;
+
+    assert_eq!(
+        xck::symmetric::encrypt_alloc(algorithm, &TEST_KEY, &TEST_NONCE, &[], &TEST_MESSAGE)
             .unwrap(),
         TEST_CIPHER
     );
+
+    assert_eq!(
+        xck::symmetric::decrypt_alloc(algorithm, &TEST_KEY, &TEST_NONCE, &[], &TEST_CIPHER)
+            .unwrap(),
+        TEST_MESSAGE
+    );
+
+    assert_eq!(
+        xck::symmetric::encrypt_alloc(algorithm, &TEST_KEY[..16], &TEST_NONCE, &[], &TEST_MESSAGE)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidKeyLength
+    );
+
+    assert_eq!(
+        xck::symmetric::encrypt_alloc(algorithm, &TEST_KEY, &[0u8; 24], &[], &TEST_MESSAGE)
+            .unwrap_err()
+            .kind(),
+        xck::ErrorKind::InvalidLength
+    );
+}
+
+#[test]
+fn aead_encrypt_in_place() {
+    for algorithm in xck::symmetric::AeadAlgorithm::ALL {
+        let key = vec![7u8; algorithm.key_len()];
+
+        let nonce = vec![9u8; algorithm.nonce_len()];
+
+        let mut buffer = b"hello".to_vec();
+
+        xck::symmetric::encrypt(algorithm, &key, &nonce, b"aad", &mut buffer).unwrap();
+
+        assert_eq!(buffer.len(), 5 + algorithm.tag_len());
+
+        xck::symmetric::decrypt(algorithm, &key, &nonce, b"aad", &mut buffer).unwrap();
+
+        assert_eq!(buffer, b"hello");
+    }
 }
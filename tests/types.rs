@@ -0,0 +1,22 @@
+#[test]
+fn secret_debug_is_redacted() {
+    let key = xck::types::Aes256GcmKey::from([7u8; 32]);
+
+    assert_eq!(format!("{:?}", key), "Aes256GcmKey(REDACTED)");
+}
+
+#[test]
+fn try_from_slice() {
+    assert!(xck::types::Ed25519SecretKey::try_from([1u8; 32].as_slice()).is_ok());
+
+    assert!(xck::types::Ed25519SecretKey::try_from([1u8; 31].as_slice()).is_err());
+
+    assert!(xck::types::Nonce12::try_from([1u8; 24].as_slice()).is_err());
+
+    assert_eq!(
+        xck::types::Nonce24::try_from([3u8; 24].as_slice())
+            .unwrap()
+            .to_bytes(),
+        [3u8; 24]
+    );
+}